@@ -7,7 +7,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     context::{TraverseCtx, TraverseCtxState},
-    tpl::opcodes::prop_op,
+    tpl::opcodes::{event_modifier, prop_op},
 };
 
 pub fn compile_chunk<'a>(
@@ -98,12 +98,18 @@ fn update_prop_op_codes<'a>(
                     let ty = v & prop_op::TYPE_MASK;
                     if ty != prop_op::SET_NODE && ty != prop_op::COMMON && ty != prop_op::DIRECTIVE
                     {
-                        let i = v >> prop_op::DATA_SHIFT;
+                        // Event ops store modifiers before the string index.
+                        let key_shift = if ty == prop_op::EVENT {
+                            prop_op::DATA_SHIFT + event_modifier::KEY_SHIFT
+                        } else {
+                            prop_op::DATA_SHIFT
+                        };
+                        let i = v >> key_shift;
                         let s = &tpl_strings[i as usize];
                         if let ArrayExpressionElement::StringLiteral(s) = s {
                             if let Some(new_index) = strings.get(s.value.as_str()) {
-                                op.value = ((v & ((1 << prop_op::DATA_SHIFT) - 1))
-                                    | ((*new_index as u32) << prop_op::DATA_SHIFT))
+                                op.value = ((v & ((1 << key_shift) - 1))
+                                    | ((*new_index as u32) << key_shift))
                                     as f64;
                             }
                         }
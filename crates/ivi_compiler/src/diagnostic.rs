@@ -0,0 +1,216 @@
+use std::fmt;
+
+use oxc_diagnostics::{GraphicalReportHandler, LabeledSpan, NamedSource, OxcDiagnostic, Severity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+impl DiagnosticSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Advice => "advice",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub code: Option<String>,
+    pub message: String,
+    // Primary spans.
+    pub labels: Vec<DiagnosticLabel>,
+    pub help: Option<String>,
+    // Secondary spans that are related to primary spans.
+    pub related: Vec<DiagnosticLabel>,
+}
+
+// Source span with a 1-based line and column of its start position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticLabel {
+    pub start: u32,
+    pub end: u32,
+    pub line: u32,
+    pub column: u32,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticFormat {
+    // Report with source code snippets.
+    Graphical,
+    // `file:line:column: severity[code]: message` lines.
+    Plain,
+    Json,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(diagnostic: OxcDiagnostic, source_text: &str) -> Self {
+        let diagnostic = diagnostic.inner_owned();
+        let labels = diagnostic.labels.as_slice();
+        // The first label is primary when labels aren't explicitly marked.
+        let has_primary = labels.iter().any(LabeledSpan::primary);
+        let (primary, related): (Vec<_>, Vec<_>) = labels
+            .iter()
+            .enumerate()
+            .partition(|(i, l)| if has_primary { l.primary() } else { *i == 0 });
+        let into_label = |(_, label): (usize, &LabeledSpan)| {
+            let start = label.offset();
+            let (line, column) = line_column(source_text, start);
+            DiagnosticLabel {
+                start,
+                end: start + label.len(),
+                line,
+                column,
+                message: label.label().map(str::to_string),
+            }
+        };
+        Self {
+            severity: match diagnostic.severity {
+                Severity::Error => DiagnosticSeverity::Error,
+                Severity::Warning => DiagnosticSeverity::Warning,
+                Severity::Advice => DiagnosticSeverity::Advice,
+            },
+            code: diagnostic.code.number.map(|n| n.to_string()),
+            message: diagnostic.message.to_string(),
+            labels: primary.into_iter().map(into_label).collect(),
+            help: diagnostic.help.map(|h| h.to_string()),
+            related: related.into_iter().map(into_label).collect(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+
+    pub fn render(&self, filename: &str, source_text: &str, format: DiagnosticFormat) -> String {
+        match format {
+            DiagnosticFormat::Graphical => self.render_graphical(filename, source_text),
+            DiagnosticFormat::Plain => self.render_plain(filename),
+            DiagnosticFormat::Json => self.to_json(filename).to_string(),
+        }
+    }
+
+    fn render_graphical(&self, filename: &str, source_text: &str) -> String {
+        let mut diagnostic =
+            OxcDiagnostic::error(self.message.clone()).with_severity(match self.severity {
+                DiagnosticSeverity::Error => Severity::Error,
+                DiagnosticSeverity::Warning => Severity::Warning,
+                DiagnosticSeverity::Advice => Severity::Advice,
+            });
+        if let Some(code) = &self.code {
+            diagnostic = diagnostic.with_error_code("ivi", code.clone());
+        }
+        if let Some(help) = &self.help {
+            diagnostic = diagnostic.with_help(help.clone());
+        }
+        let labels =
+            self.labels.iter().map(|l| (l, true)).chain(self.related.iter().map(|l| (l, false)));
+        diagnostic = diagnostic.with_labels(labels.map(|(l, primary)| {
+            let span = (l.start, l.end - l.start);
+            if primary {
+                LabeledSpan::new_primary_with_span(l.message.clone(), span)
+            } else {
+                LabeledSpan::new_with_span(l.message.clone(), span)
+            }
+        }));
+
+        let report_handler = GraphicalReportHandler::new();
+        let mut s = String::new();
+        let source = NamedSource::new(filename, source_text.to_string());
+        let _ = report_handler.render_report(&mut s, diagnostic.with_source_code(source).as_ref());
+        s
+    }
+
+    fn render_plain(&self, filename: &str) -> String {
+        let mut s = filename.to_string();
+        if let Some(label) = self.labels.first() {
+            s.push_str(&format!(":{}:{}", label.line, label.column));
+        }
+        s.push_str(&format!(": {self}"));
+        for label in self.labels.iter().chain(&self.related) {
+            if let Some(message) = &label.message {
+                s.push_str(&format!("\n  {}:{}: {message}", label.line, label.column));
+            }
+        }
+        if let Some(help) = &self.help {
+            s.push_str(&format!("\n  help: {help}"));
+        }
+        s
+    }
+
+    fn to_json(&self, filename: &str) -> serde_json::Value {
+        let labels = |labels: &[DiagnosticLabel]| {
+            labels
+                .iter()
+                .map(|l| {
+                    serde_json::json!({
+                        "start": l.start,
+                        "end": l.end,
+                        "line": l.line,
+                        "column": l.column,
+                        "message": l.message,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        serde_json::json!({
+            "file": filename,
+            "severity": self.severity.as_str(),
+            "code": self.code,
+            "message": self.message,
+            "labels": labels(&self.labels),
+            "help": self.help,
+            "related": labels(&self.related),
+        })
+    }
+}
+
+// severity[code]: message
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.severity.as_str())?;
+        if let Some(code) = &self.code {
+            write!(f, "[{code}]")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+// 1-based line and column of a byte offset, offsets inside of multi-byte
+// characters are moved to the start of the character.
+pub fn line_column(source_text: &str, offset: u32) -> (u32, u32) {
+    let offset = source_text.floor_char_boundary(offset as usize);
+    let line_start = source_text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = source_text[..line_start].matches('\n').count() as u32 + 1;
+    let column = source_text[line_start..offset].chars().count() as u32 + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::line_column;
+
+    #[test]
+    fn line_column_offsets() {
+        let source_text = "a\n€b\n";
+        assert_eq!(line_column(source_text, 0), (1, 1));
+        assert_eq!(line_column(source_text, 2), (2, 1));
+        assert_eq!(line_column(source_text, 5), (2, 2));
+        assert_eq!(line_column(source_text, 7), (3, 1));
+        assert_eq!(line_column(source_text, 100), (3, 1));
+    }
+
+    #[test]
+    fn line_column_char_boundary() {
+        let source_text = "a\n€b\n";
+        assert_eq!(line_column(source_text, 3), (2, 1));
+        assert_eq!(line_column(source_text, 4), (2, 1));
+    }
+}
@@ -1,9 +1,11 @@
-use oxc_allocator::Vec as ArenaVec;
+use oxc_allocator::{Box as ArenaBox, Vec as ArenaVec};
 use oxc_ast::{
     NONE,
     ast::{
-        Expression, IdentifierName, ImportDeclaration, ImportDeclarationSpecifier,
-        ImportOrExportKind, ImportSpecifier, ModuleExportName, Statement, Str, StringLiteral,
+        BindingPattern, BindingProperty, CallExpression, Expression, IdentifierName,
+        IdentifierReference, ImportDeclaration, ImportDeclarationSpecifier, ImportOrExportKind,
+        ImportSpecifier, ModuleExportName, ObjectPattern, PropertyKey, Statement, Str,
+        StringLiteral, VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
     },
 };
 use oxc_semantic::SymbolFlags;
@@ -12,15 +14,31 @@ use oxc_traverse::BoundIdentifier;
 
 use crate::context::TraverseCtx;
 
+// How template helpers are imported.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    // import { _T } from "ivi";
+    Static,
+    // const { _T } = require("ivi");
+    Require,
+}
+
 #[derive(Default)]
 pub struct ImportSymbols<'a> {
-    descriptor_id: Option<BoundIdentifier<'a>>,  // _T
-    html_id: Option<BoundIdentifier<'a>>,        // _hN
-    html_el_id: Option<BoundIdentifier<'a>>,     // _hE
-    svg_id: Option<BoundIdentifier<'a>>,         // _sN
-    svg_el_id: Option<BoundIdentifier<'a>>,      // _sE
-    tpl_id: Option<BoundIdentifier<'a>>,         // _t
-    empty_array_id: Option<BoundIdentifier<'a>>, // _t
+    descriptor_id: Option<BoundIdentifier<'a>>,         // _T
+    dynamic_descriptor_id: Option<BoundIdentifier<'a>>, // _Td
+    html_id: Option<BoundIdentifier<'a>>,               // _hN
+    html_el_id: Option<BoundIdentifier<'a>>,            // _hE
+    html_dynamic_id: Option<BoundIdentifier<'a>>,       // _hD
+    svg_id: Option<BoundIdentifier<'a>>,                // _sN
+    svg_el_id: Option<BoundIdentifier<'a>>,             // _sE
+    svg_dynamic_id: Option<BoundIdentifier<'a>>,        // _sD
+    tpl_id: Option<BoundIdentifier<'a>>,                // _t
+    bind_id: Option<BoundIdentifier<'a>>,               // _b
+    empty_array_id: Option<BoundIdentifier<'a>>,        // _t
+    dev_id: Option<BoundIdentifier<'a>>,                // _dev
+    hmr_template_id: Option<BoundIdentifier<'a>>,       // _hmrT
+    hmr_component_id: Option<BoundIdentifier<'a>>,      // _hmrC
 
     hoist_id: Option<BoundIdentifier<'a>>,
     dedupe_id: Option<BoundIdentifier<'a>>,
@@ -31,6 +49,10 @@ impl<'a> ImportSymbols<'a> {
         get(&mut self.descriptor_id, "_T", ctx)
     }
 
+    pub fn dynamic_template_descriptor(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.dynamic_descriptor_id, "_Td", ctx)
+    }
+
     pub fn html_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         get(&mut self.html_id, "_hN", ctx)
     }
@@ -39,6 +61,10 @@ impl<'a> ImportSymbols<'a> {
         get(&mut self.html_el_id, "_hE", ctx)
     }
 
+    pub fn html_dynamic_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.html_dynamic_id, "_hD", ctx)
+    }
+
     pub fn svg_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         get(&mut self.svg_id, "_sN", ctx)
     }
@@ -47,14 +73,39 @@ impl<'a> ImportSymbols<'a> {
         get(&mut self.svg_el_id, "_sE", ctx)
     }
 
+    pub fn svg_dynamic_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.svg_dynamic_id, "_sD", ctx)
+    }
+
     pub fn create_from_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         get(&mut self.tpl_id, "_t", ctx)
     }
 
+    pub fn bind(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.bind_id, "_b", ctx)
+    }
+
     pub fn empty_array(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         get(&mut self.empty_array_id, "EMPTY_ARRAY", ctx)
     }
 
+    pub fn dev(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.dev_id, "_dev", ctx)
+    }
+
+    pub fn hmr_template(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.hmr_template_id, "_hmrT", ctx)
+    }
+
+    pub fn hmr_component(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        get(&mut self.hmr_component_id, "_hmrC", ctx)
+    }
+
+    // Name of the `_hmrC` binding, `None` when components weren't registered.
+    pub fn hmr_component_name(&self) -> Option<&str> {
+        self.hmr_component_id.as_ref().map(|id| id.name.as_str())
+    }
+
     pub fn hoist(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
         get(&mut self.hoist_id, "hoist", ctx)
     }
@@ -63,62 +114,123 @@ impl<'a> ImportSymbols<'a> {
         get(&mut self.dedupe_id, "dedupe", ctx)
     }
 
-    pub fn create_import_statements(&self, ctx: &mut TraverseCtx<'a>) -> Vec<Statement<'a>> {
+    pub fn create_import_statements(
+        &self,
+        runtime_module: &str,
+        optimizer_module: &str,
+        kind: ImportKind,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Vec<Statement<'a>> {
         let mut imports = Vec::new();
-        let mut specifiers = ArenaVec::new_in(ctx);
-        if let Some(id) = &self.descriptor_id {
-            specifiers.push(spec("_T", id, ctx));
-        }
-        if let Some(id) = &self.html_id {
-            specifiers.push(spec("_hN", id, ctx));
-        }
-        if let Some(id) = &self.html_el_id {
-            specifiers.push(spec("_hE", id, ctx));
-        }
-        if let Some(id) = &self.svg_id {
-            specifiers.push(spec("_sN", id, ctx));
-        }
-        if let Some(id) = &self.svg_el_id {
-            specifiers.push(spec("_sE", id, ctx));
-        }
-        if let Some(id) = &self.tpl_id {
-            specifiers.push(spec("_t", id, ctx));
-        }
-        if let Some(id) = &self.empty_array_id {
-            specifiers.push(spec("EMPTY_ARRAY", id, ctx));
-        }
+        let specifiers: Vec<_> = [
+            ("_T", &self.descriptor_id),
+            ("_Td", &self.dynamic_descriptor_id),
+            ("_hN", &self.html_id),
+            ("_hE", &self.html_el_id),
+            ("_hD", &self.html_dynamic_id),
+            ("_sN", &self.svg_id),
+            ("_sE", &self.svg_el_id),
+            ("_sD", &self.svg_dynamic_id),
+            ("_t", &self.tpl_id),
+            ("_b", &self.bind_id),
+            ("EMPTY_ARRAY", &self.empty_array_id),
+            ("_dev", &self.dev_id),
+            ("_hmrT", &self.hmr_template_id),
+            ("_hmrC", &self.hmr_component_id),
+        ]
+        .into_iter()
+        .filter_map(|(name, id)| id.as_ref().map(|id| (name, id)))
+        .collect();
         if !specifiers.is_empty() {
-            imports.push(Statement::ImportDeclaration(ImportDeclaration::boxed(
-                SPAN,
-                Some(specifiers),
-                StringLiteral::new(SPAN, Str::from_str_in("ivi", ctx), None, ctx),
-                None,
-                NONE,
-                ImportOrExportKind::Value,
-                ctx,
-            )));
+            imports.push(import_statement(runtime_module, &specifiers, kind, ctx));
         }
 
-        let mut specifiers = ArenaVec::new_in(ctx);
-        if let Some(id) = &self.hoist_id {
-            specifiers.push(spec("hoist", id, ctx));
+        let specifiers: Vec<_> = [("hoist", &self.hoist_id), ("dedupe", &self.dedupe_id)]
+            .into_iter()
+            .filter_map(|(name, id)| id.as_ref().map(|id| (name, id)))
+            .collect();
+        if !specifiers.is_empty() {
+            imports.push(import_statement(optimizer_module, &specifiers, kind, ctx));
         }
-        if let Some(id) = &self.dedupe_id {
-            specifiers.push(spec("dedupe", id, ctx));
+
+        imports
+    }
+}
+
+// import { name as id } from "source";
+// const { name: id } = require("source");
+fn import_statement<'a>(
+    source: &str,
+    specifiers: &[(&'static str, &BoundIdentifier<'a>)],
+    kind: ImportKind,
+    ctx: &mut TraverseCtx<'a>,
+) -> Statement<'a> {
+    let source = StringLiteral::new(SPAN, Str::from_str_in(source, ctx), None, ctx);
+    if kind == ImportKind::Require {
+        let mut properties = ArenaVec::with_capacity_in(specifiers.len(), ctx);
+        for (name, id) in specifiers {
+            properties.push(BindingProperty::new(
+                SPAN,
+                PropertyKey::StaticIdentifier(IdentifierName::boxed(
+                    SPAN,
+                    Str::from_str_in(name, ctx),
+                    ctx,
+                )),
+                id.create_binding_pattern(ctx),
+                *name == id.name.as_str(),
+                false,
+                ctx,
+            ));
         }
-        if !specifiers.is_empty() {
-            imports.push(Statement::ImportDeclaration(ImportDeclaration::boxed(
+        let require = Expression::CallExpression(CallExpression::boxed(
+            SPAN,
+            Expression::Identifier(IdentifierReference::boxed(
                 SPAN,
-                Some(specifiers),
-                StringLiteral::new(SPAN, Str::from_str_in("oveo", ctx), None, ctx),
-                None,
-                NONE,
-                ImportOrExportKind::Value,
+                Str::from_str_in("require", ctx),
+                ctx,
+            )),
+            NONE,
+            ArenaVec::from_value_in(
+                Expression::StringLiteral(ArenaBox::new_in(source, ctx)).into(),
+                ctx,
+            ),
+            false,
+            ctx,
+        ));
+        Statement::VariableDeclaration(VariableDeclaration::boxed(
+            SPAN,
+            VariableDeclarationKind::Const,
+            ArenaVec::from_value_in(
+                VariableDeclarator::new(
+                    SPAN,
+                    VariableDeclarationKind::Const,
+                    BindingPattern::ObjectPattern(ObjectPattern::boxed(
+                        SPAN, properties, NONE, ctx,
+                    )),
+                    NONE,
+                    Some(require),
+                    false,
+                    ctx,
+                ),
                 ctx,
-            )));
+            ),
+            false,
+            ctx,
+        ))
+    } else {
+        let mut import_specifiers = ArenaVec::with_capacity_in(specifiers.len(), ctx);
+        for (name, id) in specifiers {
+            import_specifiers.push(spec(name, id, ctx));
         }
-
-        imports
+        Statement::ImportDeclaration(ImportDeclaration::boxed(
+            SPAN,
+            Some(import_specifiers),
+            source,
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+            ctx,
+        ))
     }
 }
 
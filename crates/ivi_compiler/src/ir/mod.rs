@@ -0,0 +1,375 @@
+//! Template IR.
+//!
+//! Source-level representation of `html` and `svg` templates that can be used
+//! by linters, formatters and editors without running the full transform.
+//! Templates are parsed from template strings (quasis) and spans of template
+//! expressions, every node has a span in the source text.
+//!
+//! ```
+//! use ivi_compiler::ir::{self, Quasi, Span, Visit};
+//!
+//! // html`<div class=${cls}>text</div>`
+//! let quasis = [
+//!     Quasi::new("<div class=", Span::new(5, 16)),
+//!     Quasi::new(">text</div>", Span::new(22, 33)),
+//! ];
+//! let template = ir::parse_template_str(&quasis, &[Span::new(18, 21)]).unwrap();
+//!
+//! struct Tags(Vec<String>);
+//!
+//! impl Visit for Tags {
+//!     fn visit_element(&mut self, element: &ir::Element) {
+//!         if let ir::Tag::Static(name) = &element.tag {
+//!             self.0.push(name.value.clone());
+//!         }
+//!         ir::walk_element(self, element);
+//!     }
+//! }
+//!
+//! let mut tags = Tags(Vec::new());
+//! tags.visit_template(&template);
+//! assert_eq!(tags.0, ["div"]);
+//! ```
+//!
+//! IR doesn't apply compiler transformations: boolean attributes aren't
+//! converted into properties, bindings aren't expanded into properties and
+//! events, and whitespace-only text nodes that are removed by the compiler
+//! aren't included.
+
+#![warn(missing_docs)]
+
+use std::borrow::Cow;
+
+use oxc_ast::ast::TemplateLiteral;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::GetSpan;
+pub use oxc_span::Span;
+
+use crate::WarningCode;
+
+mod parser;
+mod visit;
+
+pub use visit::{Visit, walk_element, walk_node, walk_property, walk_template};
+
+/// Template string with a span of its raw text in the source.
+///
+/// Text is a cooked string, positions inside the string are mapped to the
+/// source through the raw text with escape sequences.
+#[derive(Debug, Clone, Copy)]
+pub struct Quasi<'a> {
+    /// Cooked template string.
+    pub text: &'a str,
+    /// Raw template string as it is written in the source.
+    pub raw: &'a str,
+    /// Span of the raw template string.
+    pub span: Span,
+}
+
+impl<'a> Quasi<'a> {
+    /// Template string without escape sequences.
+    pub fn new(text: &'a str, span: Span) -> Self {
+        Self { text, raw: text, span }
+    }
+
+    /// Template string with escape sequences, `text` is the cooked string and
+    /// `raw` is the source text.
+    pub fn with_raw(text: &'a str, raw: &'a str, span: Span) -> Self {
+        Self { text, raw, span }
+    }
+}
+
+/// Template syntax error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Error message.
+    pub message: String,
+    /// Span at the error position, templates with invalid number of quasis
+    /// have an empty span.
+    pub span: Span,
+}
+
+impl ParseError {
+    pub(crate) fn new(message: impl Into<String>, span: Span) -> Self {
+        Self { message: message.into(), span }
+    }
+
+    pub(crate) fn into_oxc(self) -> OxcDiagnostic {
+        let d = OxcDiagnostic::error(Cow::Owned(self.message));
+        if self.span.is_unspanned() { d } else { d.with_label(self.span) }
+    }
+}
+
+/// Template warning, e.g. suspicious whitespace between text and expressions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// Warning code that is used to configure warning levels.
+    pub code: WarningCode,
+    /// Warning message.
+    pub message: String,
+    /// Span of the source text that causes the warning.
+    pub span: Span,
+    /// Suggestion how to fix the warning.
+    pub help: Option<String>,
+}
+
+impl ParseWarning {
+    pub(crate) fn new(code: WarningCode, message: impl Into<String>, span: Span) -> Self {
+        Self { code, message: message.into(), span, help: None }
+    }
+
+    pub(crate) fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub(crate) fn into_oxc(self) -> OxcDiagnostic {
+        let mut d = self.code.warn(Cow::Owned(self.message));
+        if let Some(help) = self.help {
+            d = d.with_help(help);
+        }
+        d.with_label(self.span)
+    }
+}
+
+/// Parses a template from template strings and spans of template expressions.
+///
+/// There should be one more quasi than expressions. Warnings are reported for
+/// suspicious whitespace between text and expressions.
+pub fn parse_template_str(
+    quasis: &[Quasi<'_>],
+    expr_spans: &[Span],
+) -> Result<Template, ParseError> {
+    parser::parse(quasis, expr_spans)
+}
+
+/// Parses a template from an oxc template literal.
+pub fn parse_template_literal(tpl: &TemplateLiteral<'_>) -> Result<Template, ParseError> {
+    let quasis: Vec<_> = tpl
+        .quasis
+        .iter()
+        .map(|q| {
+            let raw = q.value.raw.as_str();
+            Quasi::with_raw(q.value.cooked.map_or(raw, |c| c.as_str()), raw, q.span)
+        })
+        .collect();
+    let expr_spans: Vec<_> = tpl.expressions.iter().map(GetSpan::span).collect();
+    parser::parse(&quasis, &expr_spans)
+}
+
+/// Parsed template.
+#[derive(Debug)]
+pub struct Template {
+    /// Span from the start of the first template string to the end of the
+    /// last template string.
+    pub span: Span,
+    /// Root nodes.
+    pub nodes: Vec<Node>,
+    /// Warnings that were reported while parsing the template.
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// Template node.
+#[derive(Debug)]
+pub enum Node {
+    /// `<div></div>`
+    Element(Element),
+    /// Static text.
+    Text(Text),
+    /// `${expr}`
+    Expr(Expr),
+    /// `<!-- .. -->`
+    Comment(Comment),
+}
+
+impl GetSpan for Node {
+    fn span(&self) -> Span {
+        match self {
+            Self::Element(n) => n.span,
+            Self::Text(n) => n.span,
+            Self::Expr(n) => n.span,
+            Self::Comment(n) => n.span,
+        }
+    }
+}
+
+/// `<div a="1">..</div>`, `<input />`, `<${tag}>..</>`
+#[derive(Debug)]
+pub struct Element {
+    /// Span from the start of the opening tag to the end of the closing tag.
+    pub span: Span,
+    /// Tag name.
+    pub tag: Tag,
+    /// Attributes, properties, events and directives in the source order.
+    pub properties: Vec<Property>,
+    /// Child nodes.
+    pub children: Vec<Node>,
+    /// HTML void element without a closing tag (`<input>`).
+    pub void: bool,
+    /// `<div />`
+    pub self_closing: bool,
+}
+
+/// Element tag name.
+#[derive(Debug)]
+pub enum Tag {
+    /// `<div>`
+    Static(Name),
+    /// `<${tag}>`
+    Expr(Expr),
+}
+
+/// Text with whitespace collapsed the same way as in compiled templates.
+#[derive(Debug)]
+pub struct Text {
+    /// Span of the text including collapsed whitespace.
+    pub span: Span,
+    /// Text with collapsed whitespace.
+    pub value: String,
+}
+
+/// Template expression.
+#[derive(Debug, Clone, Copy)]
+pub struct Expr {
+    /// Span of the expression without `${` and `}` delimiters.
+    pub span: Span,
+    /// Index in template expressions.
+    pub index: usize,
+}
+
+/// `<!-- .. -->`
+#[derive(Debug)]
+pub struct Comment {
+    /// Span of the comment including delimiters.
+    pub span: Span,
+    /// Comment text including `<!` and `>` delimiters.
+    pub value: String,
+}
+
+/// Tag name, property name or event modifier.
+#[derive(Debug, Clone)]
+pub struct Name {
+    /// Span of the name without prefixes (`.`, `*`, `~`, `@`, `bind:`).
+    pub span: Span,
+    /// Name as it is written in the source.
+    pub value: String,
+}
+
+/// Element property.
+#[derive(Debug)]
+pub enum Property {
+    /// `name="value"`, `name=${v}`, `name`
+    Attribute(Attribute),
+    /// `.name=${v}`
+    Value(ValueProperty),
+    /// `*name=${v}`
+    DOMValue(ValueProperty),
+    /// `~name="value"`, `~name=${v}`
+    Style(Style),
+    /// `@name.modifier=${v}`
+    Event(Event),
+    /// `bind:name=${v}`
+    Binding(Binding),
+    /// `ref=${v}`
+    Ref(Ref),
+    /// `${v}`
+    Directive(Expr),
+}
+
+impl GetSpan for Property {
+    fn span(&self) -> Span {
+        match self {
+            Self::Attribute(p) => p.span,
+            Self::Value(p) | Self::DOMValue(p) => p.span,
+            Self::Style(p) => p.span,
+            Self::Event(p) => p.span,
+            Self::Binding(p) => p.span,
+            Self::Ref(p) => p.span,
+            Self::Directive(p) => p.span,
+        }
+    }
+}
+
+/// Element attribute.
+#[derive(Debug)]
+pub struct Attribute {
+    /// Span of the attribute including its value.
+    pub span: Span,
+    /// Attribute name.
+    pub name: Name,
+    /// Boolean attributes don't have values.
+    pub value: Option<Value>,
+}
+
+/// Attribute or style value.
+#[derive(Debug)]
+pub enum Value {
+    /// `"value"`
+    String(Str),
+    /// `${v}`
+    Expr(Expr),
+}
+
+/// Quoted string value.
+#[derive(Debug)]
+pub struct Str {
+    /// Span including quotes.
+    pub span: Span,
+    /// String without quotes.
+    pub value: String,
+}
+
+/// DOM property, `.name=${v}` or `*name=${v}`.
+#[derive(Debug)]
+pub struct ValueProperty {
+    /// Span of the property including its prefix and value.
+    pub span: Span,
+    /// Property name.
+    pub name: Name,
+    /// Property value.
+    pub value: Expr,
+}
+
+/// Style property, `~name="value"` or `~name=${v}`.
+#[derive(Debug)]
+pub struct Style {
+    /// Span of the style property including its prefix and value.
+    pub span: Span,
+    /// CSS property name.
+    pub name: Name,
+    /// CSS property value.
+    pub value: Value,
+}
+
+/// Event handler, `@name.modifier=${v}`.
+#[derive(Debug)]
+pub struct Event {
+    /// Span of the event including its prefix, modifiers and value.
+    pub span: Span,
+    /// Event name.
+    pub name: Name,
+    /// `passive`, `capture`, `once`, `prevent`, `stop` and `self`.
+    pub modifiers: Vec<Name>,
+    /// Event handler.
+    pub value: Expr,
+}
+
+/// Two-way binding, `bind:name=${[get, set]}`.
+#[derive(Debug)]
+pub struct Binding {
+    /// Span of the binding including its prefix and value.
+    pub span: Span,
+    /// Bound DOM property name.
+    pub name: Name,
+    /// `[get, set]` pair.
+    pub value: Expr,
+}
+
+/// Element reference, `ref=${v}`.
+#[derive(Debug)]
+pub struct Ref {
+    /// Span of the reference including its value.
+    pub span: Span,
+    /// Reference value.
+    pub value: Expr,
+}
@@ -0,0 +1,602 @@
+use oxc_span::Span;
+
+use crate::{
+    WarningCode,
+    ir::{
+        Attribute, Binding, Comment, Element, Event, Expr, Name, Node, ParseError, ParseWarning,
+        Property, Quasi, Ref, Str, Style, Tag, Template, Text, Value, ValueProperty,
+    },
+    tpl::html::is_html_void_element,
+};
+
+pub fn parse(quasis: &[Quasi<'_>], expr_spans: &[Span]) -> Result<Template, ParseError> {
+    if quasis.len() != expr_spans.len() + 1 {
+        return Err(ParseError::new(
+            format!(
+                "Template with {} expressions should have {} quasis",
+                expr_spans.len(),
+                expr_spans.len() + 1
+            ),
+            Span::default(),
+        ));
+    }
+    let mut parser = Parser::new(quasis, expr_spans);
+    let nodes = parser.parse_children_list()?;
+    Ok(Template {
+        span: Span::new(quasis[0].span.start, quasis[quasis.len() - 1].span.end),
+        nodes,
+        warnings: parser.warnings,
+    })
+}
+
+struct Parser<'a> {
+    quasis: &'a [Quasi<'a>],
+    expr_spans: &'a [Span],
+    text: &'a str,
+    expr_cursor: usize,
+    // Quasis with escape sequences.
+    escaped: Vec<bool>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(quasis: &'a [Quasi<'a>], expr_spans: &'a [Span]) -> Self {
+        Self {
+            quasis,
+            expr_spans,
+            text: quasis[0].text,
+            expr_cursor: 0,
+            escaped: quasis.iter().map(|q| q.text != q.raw).collect(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn current_element(&self) -> &Quasi<'a> {
+        &self.quasis[self.expr_cursor]
+    }
+
+    // Source position of the current char.
+    fn pos(&self) -> u32 {
+        self.pos_at(0)
+    }
+
+    // Source position of the char at the offset in the remaining text.
+    fn pos_at(&self, offset: usize) -> u32 {
+        let quasi = self.current_element();
+        let mut offset = quasi.text.len() - self.text.len() + offset;
+        if self.escaped[self.expr_cursor] {
+            offset = raw_offset(quasi.text, quasi.raw, offset);
+        }
+        (quasi.span.start + offset as u32).min(quasi.span.end)
+    }
+
+    fn span_from(&self, start: u32) -> Span {
+        Span::new(start, self.pos().max(start))
+    }
+
+    // Span of the current char, it is empty at the end of a template string.
+    fn char_span(&self) -> Span {
+        Span::new(self.pos(), self.pos_at(self.peek_char().map_or(0, char::len_utf8)))
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(message, self.char_span())
+    }
+
+    fn is_end(&self) -> bool {
+        self.text.is_empty() && self.expr_cursor == self.expr_spans.len()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.text.chars().next()
+    }
+
+    fn peek_nth_char(&self, n: usize) -> Option<char> {
+        self.text.chars().nth(n)
+    }
+
+    fn try_consume_char(&mut self, expected: char) -> Option<char> {
+        if let Some(c) = self.text.chars().next()
+            && c == expected
+        {
+            self.text = &self.text[expected.len_utf8()..];
+            Some(expected)
+        } else {
+            None
+        }
+    }
+
+    fn consume_char(&mut self, expected: char) -> Result<(), ParseError> {
+        if let Some(c) = self.text.chars().next()
+            && c == expected
+        {
+            self.text = &self.text[expected.len_utf8()..];
+            Ok(())
+        } else {
+            let parts = self.text.split_at(self.text.len().min(10));
+            Err(self.error(format!("Expected a '{expected}' char: {}", parts.0)))
+        }
+    }
+
+    fn advance(&mut self, i: usize) {
+        self.text = &self.text[i..];
+    }
+
+    fn consume_expr(&mut self) -> Result<Expr, ParseError> {
+        if self.text.is_empty() && (self.expr_cursor) < self.expr_spans.len() {
+            let index = self.expr_cursor;
+            self.expr_cursor += 1;
+            self.text = self.current_element().text;
+            Ok(Expr { span: self.expr_spans[index], index })
+        } else {
+            Err(self.error("Expected an expression"))
+        }
+    }
+
+    fn consume_whitespace(&mut self) -> WhitespaceState {
+        let mut state = 0;
+        let mut len = self.text.len();
+        for (i, c) in self.text.char_indices() {
+            match c {
+                ' ' | '\t' => {
+                    state |= WhitespaceState::WHITESPACE;
+                }
+                '\n' | '\r' => {
+                    state |= WhitespaceState::WHITESPACE | WhitespaceState::CONTAINS_NEWLINE;
+                }
+                '\x0b' => {
+                    state |= WhitespaceState::WHITESPACE | WhitespaceState::CONTAINS_VERTICAL_TAB;
+                }
+                _ => {
+                    len = i;
+                    break;
+                }
+            }
+        }
+        if len == 0 {
+            WhitespaceState(0)
+        } else {
+            self.advance(len);
+            WhitespaceState(state)
+        }
+    }
+
+    // Consumes a name that contains only chars accepted by `f`.
+    fn parse_name(
+        &mut self,
+        f: impl Fn(usize, char) -> bool,
+        kind: &str,
+    ) -> Result<Name, ParseError> {
+        let start = self.pos();
+        let mut len = self.text.len();
+        for (i, c) in self.text.char_indices() {
+            if !f(i, c) {
+                len = i;
+                break;
+            }
+        }
+        if len > 0 {
+            let value = self.text[..len].to_string();
+            self.advance(len);
+            Ok(Name { span: self.span_from(start), value })
+        } else {
+            let parts = self.text.split_at(self.text.len().min(10));
+            Err(self.error(format!("Invalid {kind} name: {}", parts.0)))
+        }
+    }
+
+    fn parse_tag_name(&mut self) -> Result<Name, ParseError> {
+        self.parse_name(|i, c| c.is_ascii_alphanumeric() || c == '_' || (c == '-' && i > 0), "tag")
+    }
+
+    fn parse_attribute_name(&mut self) -> Result<Name, ParseError> {
+        self.parse_name(
+            |i, c| c.is_ascii_alphanumeric() || c == '_' || (c == '-' && i > 0),
+            "attribute",
+        )
+    }
+
+    fn parse_js_property(&mut self) -> Result<Name, ParseError> {
+        self.parse_name(|_, c| c.is_ascii_alphanumeric() || c == '_' || c == '$', "property")
+    }
+
+    fn parse_style_name(&mut self) -> Result<Name, ParseError> {
+        self.parse_name(|_, c| c.is_ascii_alphanumeric() || c == '-' || c == '_', "style")
+    }
+
+    // @click.once.prevent
+    fn parse_event_modifiers(&mut self) -> Result<Vec<Name>, ParseError> {
+        let mut modifiers = Vec::new();
+        while self.try_consume_char('.').is_some() {
+            let name = self.parse_js_property()?;
+            if !matches!(
+                name.value.as_str(),
+                "passive" | "capture" | "once" | "prevent" | "stop" | "self"
+            ) {
+                return Err(ParseError::new(
+                    format!("Invalid event modifier: {}", name.value),
+                    name.span,
+                ));
+            }
+            modifiers.push(name);
+        }
+        let find = |m: &str| modifiers.iter().find(|n| n.value == m);
+        if let (Some(passive), Some(prevent)) = (find("passive"), find("prevent")) {
+            // Passive listeners can't cancel events.
+            return Err(ParseError::new(
+                "Event modifiers 'passive' and 'prevent' can't be used together",
+                Span::new(
+                    passive.span.start.min(prevent.span.start),
+                    passive.span.end.max(prevent.span.end),
+                ),
+            ));
+        }
+        Ok(modifiers)
+    }
+
+    fn parse_children_list(&mut self) -> Result<Vec<Node>, ParseError> {
+        let mut children = Vec::new();
+        let mut whitespace_start = self.pos();
+        let mut whitespace_state = self.consume_whitespace();
+        while !self.is_end() {
+            let whitespace_span = self.span_from(whitespace_start);
+            if let Some(c) = self.peek_char() {
+                if c == '<' {
+                    if whitespace_state.should_insert_whitespace() {
+                        children.push(Node::Text(Text {
+                            span: whitespace_span,
+                            value: " ".to_string(),
+                        }));
+                    }
+                    match self.peek_nth_char(1) {
+                        Some('/') => {
+                            break;
+                        }
+                        Some('!') => {
+                            children.push(Node::Comment(self.parse_comment()));
+                        }
+                        _ => {
+                            children.push(Node::Element(self.parse_element()?));
+                        }
+                    }
+                } else {
+                    let prev = children.iter().rev().find(|n| !matches!(n, Node::Comment(_)));
+                    if whitespace_state.is_removed_newline() && matches!(prev, Some(Node::Expr(_)))
+                    {
+                        self.warn_removed_whitespace(whitespace_span);
+                    }
+                    let start = if whitespace_state.should_insert_whitespace() {
+                        whitespace_start
+                    } else {
+                        self.pos()
+                    };
+                    children.push(Node::Text(self.parse_text(start, whitespace_state)?));
+                }
+            } else {
+                let expr = self.consume_expr()?;
+                if whitespace_state.should_insert_whitespace() {
+                    children
+                        .push(Node::Text(Text { span: whitespace_span, value: " ".to_string() }));
+                }
+                children.push(Node::Expr(expr));
+            }
+
+            whitespace_start = self.pos();
+            whitespace_state = self.consume_whitespace();
+        }
+        Ok(children)
+    }
+
+    fn parse_comment(&mut self) -> Comment {
+        let start = self.pos();
+        let mut len = self.text.len();
+        for (i, c) in self.text.char_indices() {
+            if c == '>' {
+                len = i + 1;
+                break;
+            }
+        }
+        let value = self.text[..len].to_string();
+        self.advance(len);
+        Comment { span: self.span_from(start), value }
+    }
+
+    fn parse_element(&mut self) -> Result<Element, ParseError> {
+        let start = self.pos();
+        self.advance(1);
+        // <${tag}>
+        let tag = if self.text.is_empty() {
+            Tag::Expr(self.consume_expr()?)
+        } else {
+            Tag::Static(self.parse_tag_name()?)
+        };
+        self.consume_whitespace();
+        let properties = self.parse_attributes()?;
+
+        let mut children = Vec::new();
+        let mut void = false;
+        let mut self_closing = false;
+        if self.try_consume_char('/').is_some() {
+            self.consume_char('>')?;
+            self_closing = true;
+        } else {
+            self.consume_char('>')?;
+
+            let tag_name = match &tag {
+                Tag::Static(name) => name.value.as_str(),
+                Tag::Expr(_) => "",
+            };
+            if is_html_void_element(tag_name) {
+                void = true;
+            } else {
+                children = self.parse_children_list()?;
+                self.consume_char('<')?;
+                self.consume_char('/')?;
+                self.advance(tag_name.len());
+                self.consume_whitespace();
+                self.consume_char('>')?;
+            }
+        }
+        Ok(Element { span: self.span_from(start), tag, properties, children, void, self_closing })
+    }
+
+    fn parse_text(
+        &mut self,
+        start: u32,
+        whitespace_state: WhitespaceState,
+    ) -> Result<Text, ParseError> {
+        let mut text = String::new();
+        let mut len = self.text.len();
+        let mut whitespace_state = whitespace_state;
+        for (i, c) in self.text.char_indices() {
+            match c {
+                '<' => {
+                    len = i;
+                    break;
+                }
+                ' ' | '\t' => {
+                    whitespace_state.0 |= WhitespaceState::WHITESPACE;
+                    continue;
+                }
+                '\n' | '\r' => {
+                    whitespace_state.0 |= WhitespaceState::CONTAINS_NEWLINE;
+                    continue;
+                }
+                '\x0b' => {
+                    whitespace_state.0 |= WhitespaceState::CONTAINS_VERTICAL_TAB;
+                    continue;
+                }
+                _ => {}
+            }
+            if whitespace_state.0 & WhitespaceState::WHITESPACE != 0
+                && (whitespace_state.0
+                    & (WhitespaceState::TEXT_CONTENT | WhitespaceState::CONTAINS_VERTICAL_TAB)
+                    != 0
+                    || whitespace_state.0 & WhitespaceState::CONTAINS_NEWLINE == 0)
+            {
+                text.push(' ');
+            }
+            whitespace_state.0 = WhitespaceState::TEXT_CONTENT;
+            text.push(c);
+        }
+        if whitespace_state.should_insert_whitespace() {
+            text.push(' ');
+        } else if len == self.text.len()
+            && self.expr_cursor < self.expr_spans.len()
+            && whitespace_state.0 & WhitespaceState::TEXT_CONTENT != 0
+            && whitespace_state.is_removed_newline()
+        {
+            // Text is followed by an expression.
+            let content = self.text.trim_end_matches([' ', '\t', '\n', '\r']).len();
+            self.warn_removed_whitespace(Span::new(self.pos_at(content), self.pos_at(len)));
+        }
+        self.advance(len);
+        if text.len() <= (1 << 16) {
+            Ok(Text { span: self.span_from(start), value: text })
+        } else {
+            // Text nodes are splitted into two nodes when they exceed their length limit (64k).
+            // https://github.com/chromium/chromium/blob/91159249db3086f17b28b7a060f55ec0345c24c7/third_party/blink/renderer/core/dom/text.h#L42
+            Err(ParseError::new("Text is too long (>64Kb)", self.span_from(start)))
+        }
+    }
+
+    fn warn_removed_whitespace(&mut self, span: Span) {
+        self.warnings.push(
+            ParseWarning::new(
+                WarningCode::SuspiciousWhitespace,
+                "Whitespace between text and expression is removed",
+                span,
+            )
+            .with_help(
+                "Whitespace that contains a newline is removed, put text and expression on the \
+                 same line or use '\\v' to insert a whitespace",
+            ),
+        );
+    }
+
+    fn parse_attributes(&mut self) -> Result<Vec<Property>, ParseError> {
+        let mut properties = Vec::new();
+
+        while !self.is_end() {
+            let start = self.pos();
+            if let Some(c) = self.peek_char() {
+                let property = match c {
+                    '/' | '>' => {
+                        return Ok(properties);
+                    }
+                    '.' | '*' => {
+                        self.advance(1);
+                        let name = self.parse_js_property()?;
+                        self.consume_char('=')?;
+                        let value = self.consume_expr()?;
+                        let p = ValueProperty { span: self.span_from(start), name, value };
+                        if c == '.' { Property::Value(p) } else { Property::DOMValue(p) }
+                    }
+                    '@' => {
+                        self.advance(1);
+                        let name = self.parse_js_property()?;
+                        let modifiers = self.parse_event_modifiers()?;
+                        self.consume_char('=')?;
+                        let value = self.consume_expr()?;
+                        Property::Event(Event {
+                            span: self.span_from(start),
+                            name,
+                            modifiers,
+                            value,
+                        })
+                    }
+                    '~' => {
+                        self.advance(1);
+                        let name = self.parse_style_name()?;
+                        self.consume_char('=')?;
+                        let value = if self.peek_char().is_some() {
+                            Value::String(self.parse_attribute_string()?)
+                        } else {
+                            Value::Expr(self.consume_expr()?)
+                        };
+                        Property::Style(Style { span: self.span_from(start), name, value })
+                    }
+                    // bind:value=${[get, set]}
+                    _ if self.text.starts_with("bind:") => {
+                        self.advance(5);
+                        let name = self.parse_js_property()?;
+                        self.consume_char('=')?;
+                        let value = self.consume_expr()?;
+                        Property::Binding(Binding { span: self.span_from(start), name, value })
+                    }
+                    _ => {
+                        let name = self.parse_attribute_name()?;
+                        if self.try_consume_char('=').is_some() {
+                            let value = if let Some('"') = self.peek_char() {
+                                Value::String(self.parse_attribute_string()?)
+                            } else {
+                                Value::Expr(self.consume_expr()?)
+                            };
+                            match value {
+                                // ref=${expr}
+                                Value::Expr(value) if name.value == "ref" => {
+                                    Property::Ref(Ref { span: self.span_from(start), value })
+                                }
+                                value => Property::Attribute(Attribute {
+                                    span: self.span_from(start),
+                                    name,
+                                    value: Some(value),
+                                }),
+                            }
+                        } else {
+                            Property::Attribute(Attribute { span: name.span, name, value: None })
+                        }
+                    }
+                };
+                properties.push(property);
+            } else {
+                properties.push(Property::Directive(self.consume_expr()?))
+            }
+            self.consume_whitespace();
+        }
+
+        let parts = self.text.split_at(self.text.len().min(10));
+        Err(self.error(format!("Expected a '>' char: {}", parts.0)))
+    }
+
+    fn parse_attribute_string(&mut self) -> Result<Str, ParseError> {
+        let start = self.pos();
+        let delim;
+        let mut chars = self.text.char_indices();
+        if let Some((_, c)) = chars.next() {
+            match c {
+                '\'' | '"' => {
+                    delim = c;
+                }
+                _ => {
+                    return Err(self.error("Invalid string value, it should start with '\"' char."));
+                }
+            }
+            for (i, c) in chars {
+                if c == delim {
+                    let value = self.text[1..i].to_string();
+                    self.advance(i + 1);
+                    return Ok(Str { span: self.span_from(start), value });
+                }
+            }
+            Err(ParseError::new(
+                "Invalid string value, it should end with '\"' char.",
+                Span::new(start, self.pos_at(self.text.len())),
+            ))
+        } else {
+            Err(self.error("Invalid string value"))
+        }
+    }
+}
+
+// Maps an offset in the cooked text to an offset in the raw text.
+fn raw_offset(cooked: &str, raw: &str, offset: usize) -> usize {
+    let mut cooked_chars = cooked.chars();
+    let mut cooked_offset = 0;
+    let mut i = 0;
+    // Invalid escape sequences can end inside of a char.
+    while let Some(rest) = raw.get(i..)
+        && !rest.is_empty()
+    {
+        let (len, cooked) = raw_char_len(rest);
+        if cooked {
+            if cooked_offset >= offset {
+                break;
+            }
+            cooked_offset += cooked_chars.next().map_or(0, char::len_utf8);
+        }
+        i += len;
+    }
+    i.min(raw.len())
+}
+
+// Length of the raw char or escape sequence at the start of the string, and
+// whether it is cooked into a char (line continuations are removed).
+fn raw_char_len(raw: &str) -> (usize, bool) {
+    let mut chars = raw.chars();
+    match chars.next() {
+        Some('\\') => match chars.next() {
+            Some('\r') if chars.next() == Some('\n') => (3, false),
+            Some(c @ ('\r' | '\n' | '\u{2028}' | '\u{2029}')) => (1 + c.len_utf8(), false),
+            Some('x') => (4, true),
+            Some('u') if raw[2..].starts_with('{') => {
+                (raw.find('}').map_or(raw.len(), |i| i + 1), true)
+            }
+            Some('u') => {
+                // Surrogate pairs are cooked into a single char.
+                let unit = |s: &str| s.get(2..6).and_then(|h| u16::from_str_radix(h, 16).ok());
+                let pair = matches!(unit(raw), Some(0xD800..=0xDBFF))
+                    && raw[6..].starts_with("\\u")
+                    && matches!(unit(&raw[6..]), Some(0xDC00..=0xDFFF));
+                (if pair { 12 } else { 6 }, true)
+            }
+            Some(c) => (1 + c.len_utf8(), true),
+            None => (1, true),
+        },
+        Some('\r') if chars.next() == Some('\n') => (2, true),
+        Some(c) => (c.len_utf8(), true),
+        None => (0, false),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct WhitespaceState(u8);
+
+impl WhitespaceState {
+    const WHITESPACE: u8 = 1;
+    const CONTAINS_NEWLINE: u8 = 1 << 1;
+    const CONTAINS_VERTICAL_TAB: u8 = 1 << 2;
+    const TEXT_CONTENT: u8 = 1 << 3;
+
+    // Whitespace that contains a newline is removed.
+    fn is_removed_newline(self) -> bool {
+        self.0 & WhitespaceState::CONTAINS_NEWLINE != 0
+            && self.0 & WhitespaceState::CONTAINS_VERTICAL_TAB == 0
+    }
+
+    fn should_insert_whitespace(self) -> bool {
+        self.0 & WhitespaceState::WHITESPACE != 0
+            && (self.0 & WhitespaceState::CONTAINS_NEWLINE == 0
+                || self.0 & WhitespaceState::CONTAINS_VERTICAL_TAB != 0)
+    }
+}
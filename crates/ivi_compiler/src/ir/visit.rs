@@ -0,0 +1,87 @@
+use crate::ir::{Comment, Element, Expr, Node, Property, Tag, Template, Text, Value};
+
+/// Template IR visitor.
+///
+/// Default implementations visit all child nodes with `walk_*` functions,
+/// overridden methods should call them to continue traversal.
+pub trait Visit {
+    /// Visits a template.
+    fn visit_template(&mut self, template: &Template) {
+        walk_template(self, template);
+    }
+
+    /// Visits a template node.
+    fn visit_node(&mut self, node: &Node) {
+        walk_node(self, node);
+    }
+
+    /// Visits an element, its tag expression, properties and children.
+    fn visit_element(&mut self, element: &Element) {
+        walk_element(self, element);
+    }
+
+    /// Visits an element property.
+    fn visit_property(&mut self, property: &Property) {
+        walk_property(self, property);
+    }
+
+    /// Visits a text node.
+    fn visit_text(&mut self, _text: &Text) {}
+
+    /// Visits a template expression.
+    fn visit_expr(&mut self, _expr: &Expr) {}
+
+    /// Visits a comment.
+    fn visit_comment(&mut self, _comment: &Comment) {}
+}
+
+/// Visits root nodes of a template.
+pub fn walk_template<V: Visit + ?Sized>(visitor: &mut V, template: &Template) {
+    for node in &template.nodes {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits a node with a visitor method for its kind.
+pub fn walk_node<V: Visit + ?Sized>(visitor: &mut V, node: &Node) {
+    match node {
+        Node::Element(element) => visitor.visit_element(element),
+        Node::Text(text) => visitor.visit_text(text),
+        Node::Expr(expr) => visitor.visit_expr(expr),
+        Node::Comment(comment) => visitor.visit_comment(comment),
+    }
+}
+
+/// Visits a tag expression, properties and children of an element.
+pub fn walk_element<V: Visit + ?Sized>(visitor: &mut V, element: &Element) {
+    if let Tag::Expr(expr) = &element.tag {
+        visitor.visit_expr(expr);
+    }
+    for property in &element.properties {
+        visitor.visit_property(property);
+    }
+    for node in &element.children {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits expressions of a property.
+pub fn walk_property<V: Visit + ?Sized>(visitor: &mut V, property: &Property) {
+    match property {
+        Property::Attribute(p) => {
+            if let Some(Value::Expr(expr)) = &p.value {
+                visitor.visit_expr(expr);
+            }
+        }
+        Property::Value(p) | Property::DOMValue(p) => visitor.visit_expr(&p.value),
+        Property::Style(p) => {
+            if let Value::Expr(expr) = &p.value {
+                visitor.visit_expr(expr);
+            }
+        }
+        Property::Event(p) => visitor.visit_expr(&p.value),
+        Property::Binding(p) => visitor.visit_expr(&p.value),
+        Property::Ref(p) => visitor.visit_expr(&p.value),
+        Property::Directive(expr) => visitor.visit_expr(expr),
+    }
+}
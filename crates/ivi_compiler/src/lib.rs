@@ -1,29 +1,419 @@
-use std::path::PathBuf;
+use std::{
+    borrow::Cow,
+    path::{Component, Path, PathBuf},
+};
 
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::GraphicalReportHandler;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
+use oxc_sourcemap::SourceMap;
 use oxc_span::SourceType;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+pub use diagnostic::{Diagnostic, DiagnosticFormat, DiagnosticLabel, DiagnosticSeverity};
+pub use tpl::{TemplateBlockInfo, TemplateInfo, TemplateKind};
+
 mod chunk;
 mod context;
+mod diagnostic;
 mod import;
+pub mod ir;
 mod module;
-mod oveo;
+mod optimizer;
+mod sourcemap;
 mod tpl;
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct CompilerOptions {
     pub dedupe_strings: bool,
-    pub oveo: bool,
+    // Intrinsics that are emitted for javascript optimizers.
+    pub optimizer: Optimizer,
+    // Modules that export ivi templates and components.
+    pub ivi_modules: Vec<IviModule>,
+    // Module that exports template helpers (`_T`, `_hN`, `_t`, etc).
+    pub runtime_module: String,
+    // Local modules that re-export ivi templates and components.
+    pub re_exports: ReExports,
+    // Reports templates that can't be precompiled as errors.
+    pub require_precompilation: bool,
+    // Expressions that are wrapped with `hoist()` intrinsics.
+    pub hoist: HoistOptions,
+    // Attaches template source information to template descriptors.
+    pub dev: bool,
+    // Registers templates and components for Hot Module Replacement.
+    pub hmr: bool,
+    // Includes source text in the `sourcesContent` field of source maps.
+    pub sources_content: bool,
+    // Overrides reporting levels of warnings.
+    pub warnings: FxHashMap<WarningCode, WarningLevel>,
+    // Collects compiled templates in `CompilerOutput.templates`.
+    pub templates: bool,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            dedupe_strings: false,
+            optimizer: Optimizer::default(),
+            ivi_modules: vec![IviModule::new("ivi")],
+            runtime_module: "ivi".to_string(),
+            re_exports: ReExports::default(),
+            require_precompilation: false,
+            hoist: HoistOptions::default(),
+            dev: false,
+            hmr: false,
+            sources_content: true,
+            warnings: FxHashMap::default(),
+            templates: false,
+        }
+    }
+}
+
+impl CompilerOptions {
+    pub fn builder() -> CompilerOptionsBuilder {
+        CompilerOptionsBuilder::default()
+    }
+
+    pub fn warning_level(&self, code: WarningCode) -> WarningLevel {
+        if let Some(level) = self.warnings.get(&code) {
+            *level
+        } else if code == WarningCode::UnsupportedTemplate && self.require_precompilation {
+            WarningLevel::Deny
+        } else {
+            WarningLevel::Warn
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CompilerOptionsBuilder {
+    options: CompilerOptions,
+}
+
+impl CompilerOptionsBuilder {
+    pub fn dedupe_strings(mut self, dedupe_strings: bool) -> Self {
+        self.options.dedupe_strings = dedupe_strings;
+        self
+    }
+
+    pub fn optimizer(mut self, optimizer: Optimizer) -> Self {
+        self.options.optimizer = optimizer;
+        self
+    }
+
+    pub fn ivi_modules(mut self, ivi_modules: impl IntoIterator<Item = IviModule>) -> Self {
+        self.options.ivi_modules = ivi_modules.into_iter().collect();
+        self
+    }
+
+    pub fn runtime_module(mut self, runtime_module: impl Into<String>) -> Self {
+        self.options.runtime_module = runtime_module.into();
+        self
+    }
+
+    pub fn re_export(
+        mut self,
+        path: impl AsRef<Path>,
+        exports: impl IntoIterator<Item = (String, IviExport)>,
+    ) -> Self {
+        self.options.re_exports.insert(path, exports);
+        self
+    }
+
+    pub fn require_precompilation(mut self, require_precompilation: bool) -> Self {
+        self.options.require_precompilation = require_precompilation;
+        self
+    }
+
+    pub fn hoist(mut self, hoist: HoistOptions) -> Self {
+        self.options.hoist = hoist;
+        self
+    }
+
+    pub fn dev(mut self, dev: bool) -> Self {
+        self.options.dev = dev;
+        self
+    }
+
+    pub fn hmr(mut self, hmr: bool) -> Self {
+        self.options.hmr = hmr;
+        self
+    }
+
+    pub fn sources_content(mut self, sources_content: bool) -> Self {
+        self.options.sources_content = sources_content;
+        self
+    }
+
+    pub fn templates(mut self, templates: bool) -> Self {
+        self.options.templates = templates;
+        self
+    }
+
+    pub fn warning(mut self, code: WarningCode, level: WarningLevel) -> Self {
+        self.options.warnings.insert(code, level);
+        self
+    }
+
+    pub fn build(self) -> CompilerOptions {
+        self.options
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Optimizer {
+    // Module that exports `hoist()` and `dedupe()` intrinsics.
+    pub module: String,
+    // Wraps hoistable expressions with `hoist()`.
+    pub hoist: bool,
+    // Wraps static templates and opcodes with `dedupe()`.
+    pub dedupe: bool,
+    // Marks template descriptors with `/*#__PURE__*/` annotations.
+    pub pure: bool,
+}
+
+impl Optimizer {
+    // https://github.com/localvoid/oveo
+    pub fn oveo() -> Self {
+        Self { module: "oveo".to_string(), hoist: true, dedupe: true, pure: false }
+    }
+
+    pub fn none() -> Self {
+        Self { module: String::new(), hoist: false, dedupe: false, pure: false }
+    }
+}
+
+// Template descriptors are annotated as pure, so that bundlers can remove
+// templates from unused components.
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self { pure: true, ..Self::none() }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct HoistOptions {
+    // Render functions returned from `component()` factories.
+    pub components: bool,
+    // Event handlers `@click=${handler}`.
+    pub events: bool,
+    // Element directives `<div ${directive}>`.
+    pub directives: bool,
+    // `List()` key and render callbacks.
+    pub list: bool,
+    // `useEffect()`, `useAnimationFrameEffect()` and `useIdleEffect()` hooks
+    // and equality functions.
+    pub effects: bool,
+}
+
+impl HoistOptions {
+    pub const NONE: Self =
+        Self { components: false, events: false, directives: false, list: false, effects: false };
+}
+
+impl Default for HoistOptions {
+    fn default() -> Self {
+        Self { components: true, events: true, directives: true, list: true, effects: true }
+    }
+}
+
+// Stable warning codes, they are reported as `ivi(code)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningCode {
+    // `html` and `svg` templates that can't be precompiled.
+    UnsupportedTemplate,
+    // Attributes that set only the initial state of form elements:
+    // `<input checked=${v}>`.
+    FormStateAttribute,
+    // Whitespace between text and expressions that is removed because it
+    // contains a newline.
+    SuspiciousWhitespace,
+    // `innerHTML` properties with non-literal values.
+    InnerHtml,
+    // ivi modules that are imported only with `await import()`, template
+    // helpers are imported statically.
+    DynamicImport,
+}
+
+impl WarningCode {
+    pub const ALL: [Self; 5] = [
+        Self::UnsupportedTemplate,
+        Self::FormStateAttribute,
+        Self::SuspiciousWhitespace,
+        Self::InnerHtml,
+        Self::DynamicImport,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UnsupportedTemplate => "unsupported-template",
+            Self::FormStateAttribute => "form-state-attribute",
+            Self::SuspiciousWhitespace => "suspicious-whitespace",
+            Self::InnerHtml => "inner-html",
+            Self::DynamicImport => "dynamic-import",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == name)
+    }
+
+    pub(crate) fn warn(self, message: impl Into<Cow<'static, str>>) -> OxcDiagnostic {
+        OxcDiagnostic::warn(message).with_error_code("ivi", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningLevel {
+    // Warnings are ignored.
+    Allow,
+    Warn,
+    // Warnings are reported as errors.
+    Deny,
+}
+
+impl WarningLevel {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IviModule {
+    pub specifier: String,
+    // Maps exported names to ivi exports. When it is `None`, module exports
+    // have the same names as in ivi.
+    pub exports: Option<FxHashMap<String, IviExport>>,
+}
+
+impl IviModule {
+    pub fn new(specifier: impl Into<String>) -> Self {
+        Self { specifier: specifier.into(), exports: None }
+    }
+
+    pub fn with_exports(
+        specifier: impl Into<String>,
+        exports: impl IntoIterator<Item = (String, IviExport)>,
+    ) -> Self {
+        Self { specifier: specifier.into(), exports: Some(exports.into_iter().collect()) }
+    }
+}
+
+// Maps module paths to their ivi exports, e.g. `./ui.ts` with
+// `export { html as h } from "ivi"` has an `h => IviExport::Html` export.
+#[derive(Debug, Default, Clone)]
+pub struct ReExports {
+    modules: FxHashMap<PathBuf, FxHashMap<String, IviExport>>,
+}
+
+impl ReExports {
+    pub fn insert(
+        &mut self,
+        path: impl AsRef<Path>,
+        exports: impl IntoIterator<Item = (String, IviExport)>,
+    ) {
+        self.modules.entry(normalize_path(path.as_ref())).or_default().extend(exports);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    // Resolves a relative import specifier from the `importer` module.
+    pub fn resolve(
+        &self,
+        importer: &Path,
+        specifier: &str,
+    ) -> Option<&FxHashMap<String, IviExport>> {
+        if self.modules.is_empty() || !(specifier.starts_with("./") || specifier.starts_with("../"))
+        {
+            return None;
+        }
+        let path = normalize_path(&importer.parent()?.join(specifier));
+        if let Some(exports) = self.modules.get(&path) {
+            return Some(exports);
+        }
+        // Import specifiers can omit extensions and index files, and
+        // typescript modules can be imported with `.js` extensions.
+        let path_stem = strip_module_extension(&path);
+        self.modules.iter().find_map(|(p, exports)| {
+            let stem = strip_module_extension(p);
+            if stem == path
+                || stem == path_stem
+                || (stem.ends_with("index") && stem.parent() == Some(&path))
+            {
+                Some(exports)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+// Strips javascript and typescript module extensions, other extensions are
+// a part of the module name (e.g. `./button.styles`).
+fn strip_module_extension(path: &Path) -> PathBuf {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("js" | "mjs" | "cjs" | "jsx" | "ts" | "mts" | "cts" | "tsx") => path.with_extension(""),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(result.components().next_back(), Some(Component::Normal(_))) =>
+            {
+                result.pop();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IviExport {
+    Component,
+    Html,
+    Svg,
+    List,
+    UseEffect,
+    UseAnimationFrameEffect,
+    UseIdleEffect,
+}
+
+impl IviExport {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "component" => Some(Self::Component),
+            "html" => Some(Self::Html),
+            "svg" => Some(Self::Svg),
+            "List" => Some(Self::List),
+            "useEffect" => Some(Self::UseEffect),
+            "useAnimationFrameEffect" => Some(Self::UseAnimationFrameEffect),
+            "useIdleEffect" => Some(Self::UseIdleEffect),
+            _ => None,
+        }
+    }
 }
 
 pub struct CompilerOutput {
     pub code: String,
     pub map: String,
+    pub diagnostics: Vec<Diagnostic>,
+    // Compiled templates, `None` when `templates` option is disabled.
+    pub templates: Option<Vec<TemplateInfo>>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -31,78 +421,207 @@ pub enum CompilerError {
     #[error("Invalid module type: {0}")]
     ModuleType(String),
     #[error("Unable to parse javascript file: {0}")]
-    SyntaxError(String),
+    SyntaxError(Box<Diagnostic>),
     #[error("Unable to parse javascript file: {0}")]
-    SemanticError(String),
+    SemanticError(Box<Diagnostic>),
     #[error("Invalid template: {0}")]
-    InvalidTemplate(String),
+    InvalidTemplate(Box<Diagnostic>),
+    #[error("Invalid input source map: {0}")]
+    InputSourceMap(String),
+}
+
+impl CompilerError {
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        match self {
+            Self::SyntaxError(d) | Self::SemanticError(d) | Self::InvalidTemplate(d) => Some(d),
+            Self::ModuleType(_) | Self::InputSourceMap(_) => None,
+        }
+    }
+
+    // Renders error with its diagnostic in the specified format. Only graphical
+    // format is prefixed with an error kind, so that json diagnostics could be
+    // parsed and plain diagnostics start with `file:line:column:`.
+    pub fn render(&self, filename: &str, source_text: &str, format: DiagnosticFormat) -> String {
+        let prefix = match self {
+            Self::SyntaxError(_) | Self::SemanticError(_) => "Unable to parse javascript file",
+            Self::InvalidTemplate(_) => "Invalid template",
+            Self::ModuleType(_) | Self::InputSourceMap(_) => return self.to_string(),
+        };
+        match self.diagnostic() {
+            Some(d) => match format {
+                DiagnosticFormat::Graphical => {
+                    format!("{prefix}: {}", d.render(filename, source_text, format))
+                }
+                DiagnosticFormat::Plain | DiagnosticFormat::Json => {
+                    d.render(filename, source_text, format)
+                }
+            },
+            None => self.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    Js,
+    Cjs,
+    Jsx,
+    Ts,
+    Cts,
+    Tsx,
+}
+
+impl ModuleType {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "js" | "mjs" => Some(Self::Js),
+            "cjs" => Some(Self::Cjs),
+            "jsx" => Some(Self::Jsx),
+            "ts" | "mts" => Some(Self::Ts),
+            "cts" => Some(Self::Cts),
+            "tsx" => Some(Self::Tsx),
+            // Script blocks without `lang` query parameters.
+            "vue" | "svelte" | "astro" => Some(Self::Ts),
+            _ => None,
+        }
+    }
+
+    // Infers module type from a file path or a bundler module id. Script
+    // blocks are using `lang.*` query parameters, e.g.
+    // `App.vue?vue&type=script&setup=true&lang.tsx`.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let name = path.as_ref().file_name()?.to_str()?;
+        let (name, query) = name.split_once('?').unwrap_or((name, ""));
+        if let Some(lang) = query.split('&').find_map(|p| p.strip_prefix("lang.")) {
+            return Self::from_extension(lang);
+        }
+        Self::from_extension(Path::new(name).extension()?.to_str()?)
+    }
+
+    fn source_type(self) -> SourceType {
+        match self {
+            Self::Js => SourceType::mjs(),
+            Self::Cjs => SourceType::cjs(),
+            Self::Jsx => SourceType::jsx(),
+            Self::Ts => SourceType::ts(),
+            Self::Cts => SourceType::ts().with_commonjs(true),
+            Self::Tsx => SourceType::tsx(),
+        }
+    }
+}
+
+impl std::str::FromStr for ModuleType {
+    type Err = CompilerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_extension(s).ok_or_else(|| CompilerError::ModuleType(s.to_string()))
+    }
 }
 
 pub fn compile_module(
     source_text: &str,
-    module_type: &str,
+    filename: &str,
+    module_type: ModuleType,
+    input_map: Option<&str>,
     options: &CompilerOptions,
     strings: &mut FxHashSet<String>,
 ) -> Result<CompilerOutput, CompilerError> {
+    let input_map = parse_input_map(input_map)?;
     let allocator = Allocator::default();
-    let source_type = match module_type {
-        "js" => SourceType::mjs(),
-        "jsx" => SourceType::jsx(),
-        "ts" => SourceType::ts(),
-        "tsx" => SourceType::tsx(),
-        _ => return Err(CompilerError::ModuleType(module_type.to_string())),
-    };
-    let ret = Parser::new(&allocator, source_text, source_type).parse();
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(CompilerError::SyntaxError(err.to_string()));
+    let ret = Parser::new(&allocator, source_text, module_type.source_type()).parse();
+    if let Some(err) = ret.diagnostics.into_iter().next() {
+        return Err(CompilerError::SyntaxError(Box::new(Diagnostic::new(err, source_text))));
     }
 
     let mut program = ret.program;
 
     let ret = SemanticBuilder::new().with_excess_capacity(1.0).build(&program);
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(CompilerError::SemanticError(err.to_string()));
+    if let Some(err) = ret.diagnostics.into_iter().next() {
+        return Err(CompilerError::SemanticError(Box::new(Diagnostic::new(err, source_text))));
     }
 
     let scoping = ret.semantic.into_scoping();
-    let mut errors = module::compile_module(&mut program, &allocator, scoping, options, strings);
-    if let Some(err) = errors.drain(..).next() {
-        let report_handler = GraphicalReportHandler::new();
-        let mut s = String::new();
-        let _ = report_handler
-            .render_report(&mut s, err.with_source_code(source_text.to_string()).as_ref());
-        return Err(CompilerError::InvalidTemplate(s));
+    let (diagnostics, templates) =
+        module::compile_module(&mut program, &allocator, scoping, filename, options, strings);
+    let diagnostics: Vec<_> = diagnostics
+        .into_iter()
+        .filter_map(|d| {
+            let mut d = Diagnostic::new(d, source_text);
+            if d.severity == DiagnosticSeverity::Warning
+                && let Some(code) = d.code.as_deref().and_then(WarningCode::from_name)
+            {
+                match options.warning_level(code) {
+                    WarningLevel::Allow => return None,
+                    WarningLevel::Warn => {}
+                    WarningLevel::Deny => d.severity = DiagnosticSeverity::Error,
+                }
+            }
+            Some(d)
+        })
+        .collect();
+    if let Some(err) = diagnostics.iter().find(|d| d.is_error()) {
+        return Err(CompilerError::InvalidTemplate(Box::new(err.clone())));
     }
 
     let result = Codegen::new()
         .with_options(CodegenOptions {
-            source_map_path: Some(PathBuf::new()),
+            source_map_path: Some(PathBuf::from(filename)),
             ..Default::default()
         })
         .build(&program);
 
     Ok(CompilerOutput {
         code: result.code,
-        map: result.map.map_or_else(String::default, |v| v.to_json_string()),
+        map: result
+            .map
+            .map_or_else(String::default, |map| source_map_json(map, input_map.as_ref(), options)),
+        diagnostics,
+        templates,
     })
 }
 
+fn parse_input_map(input_map: Option<&str>) -> Result<Option<SourceMap<'_>>, CompilerError> {
+    input_map
+        .map(SourceMap::from_json_string)
+        .transpose()
+        .map_err(|err| CompilerError::InputSourceMap(err.to_string()))
+}
+
+fn source_map_json(
+    map: SourceMap,
+    input_map: Option<&SourceMap>,
+    options: &CompilerOptions,
+) -> String {
+    let mut map = match input_map {
+        Some(input_map) => sourcemap::compose_source_maps(&map, input_map),
+        None => map,
+    };
+    if !options.sources_content {
+        map.set_source_contents(vec![None; map.get_sources().count()]);
+    }
+    map.to_json_string()
+}
+
 pub fn compile_chunk(
     source_text: &str,
+    filename: &str,
+    input_map: Option<&str>,
+    options: &CompilerOptions,
     strings: &FxHashMap<String, u8>,
 ) -> Result<CompilerOutput, CompilerError> {
+    let input_map = parse_input_map(input_map)?;
     let allocator = Allocator::default();
     let source_type = SourceType::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(CompilerError::SyntaxError(err.to_string()));
+    if let Some(err) = ret.diagnostics.into_iter().next() {
+        return Err(CompilerError::SyntaxError(Box::new(Diagnostic::new(err, source_text))));
     }
 
     let mut program = ret.program;
 
     let ret = SemanticBuilder::new().with_excess_capacity(0.1).build(&program);
-    if let Some(err) = ret.diagnostics.first() {
-        return Err(CompilerError::SemanticError(err.to_string()));
+    if let Some(err) = ret.diagnostics.into_iter().next() {
+        return Err(CompilerError::SemanticError(Box::new(Diagnostic::new(err, source_text))));
     }
 
     let scoping = ret.semantic.into_scoping();
@@ -111,13 +630,17 @@ pub fn compile_chunk(
 
     let result = Codegen::new()
         .with_options(CodegenOptions {
-            source_map_path: Some(PathBuf::new()),
+            source_map_path: Some(PathBuf::from(filename)),
             ..Default::default()
         })
         .build(&program);
 
     Ok(CompilerOutput {
         code: result.code,
-        map: result.map.map_or_else(String::default, |v| v.to_json_string()),
+        map: result
+            .map
+            .map_or_else(String::default, |map| source_map_json(map, input_map.as_ref(), options)),
+        diagnostics: Vec::new(),
+        templates: None,
     })
 }
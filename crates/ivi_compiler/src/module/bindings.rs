@@ -0,0 +1,150 @@
+use std::collections::hash_map;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_semantic::Scoping;
+use oxc_span::GetSpan;
+use rustc_hash::FxHashMap;
+
+use super::{IviSymbol, ModuleCompiler, is_constant_binding, module_request, resolve_export};
+use crate::IviExport;
+
+impl<'a> ModuleCompiler<'a, '_> {
+    // Resolves ivi module bindings and local aliases before traversal, so that
+    // templates can use bindings that are declared below them:
+    //
+    //   function App() { return h`<div></div>`; }
+    //   const h = html;
+    pub(super) fn resolve_bindings(&mut self, program: &Program<'a>, scoping: &Scoping) {
+        loop {
+            let mut resolver =
+                BindingResolver { compiler: self, scoping, resolved: 0, unresolved: false };
+            resolver.visit_program(program);
+            // Aliases can reference aliases that are declared below them.
+            if resolver.resolved == 0 || !resolver.unresolved {
+                break;
+            }
+        }
+    }
+}
+
+struct BindingResolver<'a, 'b, 'ctx> {
+    compiler: &'b mut ModuleCompiler<'a, 'ctx>,
+    scoping: &'b Scoping,
+    // The number of bindings that were resolved in this pass.
+    resolved: usize,
+    // Some of the possible aliases weren't resolved in this pass.
+    unresolved: bool,
+}
+
+impl<'a, 'ctx> BindingResolver<'a, '_, 'ctx> {
+    fn declare(&mut self, id: &BindingIdentifier<'a>, symbol: IviSymbol) {
+        if let hash_map::Entry::Vacant(entry) = self.compiler.ivi_module.entry(id.symbol_id()) {
+            entry.insert(symbol);
+            self.resolved += 1;
+        }
+    }
+
+    fn declare_module(
+        &mut self,
+        id: &BindingIdentifier<'a>,
+        exports: Option<&'ctx FxHashMap<String, IviExport>>,
+    ) {
+        if !self.compiler.ivi_module.contains_key(&id.symbol_id()) {
+            let i = self.compiler.namespaces.len();
+            self.compiler.namespaces.push(exports);
+            self.declare(id, IviSymbol::Module(i));
+        }
+    }
+
+    fn declare_module_bindings(
+        &mut self,
+        pattern: &BindingPattern<'a>,
+        kind: VariableDeclarationKind,
+        exports: Option<&'ctx FxHashMap<String, IviExport>>,
+    ) {
+        match pattern {
+            // const ivi = require("ivi");
+            BindingPattern::BindingIdentifier(id) => {
+                if is_constant_binding(id, kind, self.scoping) {
+                    self.declare_module(id, exports);
+                }
+            }
+            // const { html, svg: s } = require("ivi");
+            BindingPattern::ObjectPattern(p) => {
+                for p in &p.properties {
+                    if let BindingPattern::BindingIdentifier(id) = &p.value
+                        && let Some(name) = p.key.static_name()
+                        && !p.computed
+                        && let Some(e) = resolve_export(exports, &name)
+                        && is_constant_binding(id, kind, self.scoping)
+                    {
+                        self.declare(id, IviSymbol::from(e));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Visit<'a> for BindingResolver<'a, '_, '_> {
+    fn visit_import_declaration(&mut self, node: &ImportDeclaration<'a>) {
+        let Some(specifiers) = &node.specifiers else {
+            return;
+        };
+        let Some(exports) = self.compiler.resolve_module(node.source.value.as_str()) else {
+            return;
+        };
+        for spec in specifiers {
+            match spec {
+                // import { imported } from "source"
+                // import { imported as local } from "source"
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    if let Some(e) = resolve_export(exports, spec.imported.name().as_str()) {
+                        self.declare(&spec.local, IviSymbol::from(e));
+                    }
+                }
+                // import * as local from "source"
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
+                    self.declare_module(&spec.local, exports);
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {}
+            }
+        }
+    }
+
+    fn visit_variable_declarator(&mut self, node: &VariableDeclarator<'a>) {
+        walk::walk_variable_declarator(self, node);
+        let Some(init) = &node.init else {
+            return;
+        };
+        // Resolve ivi module
+        // const ivi = require("ivi");
+        // const { html } = await import("ivi");
+        //
+        // Follow local aliases
+        // const h = html;
+        if let Some((source, require)) = module_request(init, self.scoping) {
+            if let Some(exports) = self.compiler.resolve_module(source) {
+                self.compiler.require |= require;
+                if !require {
+                    self.compiler.dynamic_import.get_or_insert(init.span());
+                }
+                self.declare_module_bindings(&node.id, node.kind, exports);
+            }
+        } else if let BindingPattern::BindingIdentifier(id) = &node.id
+            && !self.compiler.ivi_module.contains_key(&id.symbol_id())
+            && is_constant_binding(id, node.kind, self.scoping)
+        {
+            if let Some(s) = self.compiler.resolve(init, self.scoping) {
+                self.declare(id, s);
+            } else if matches!(
+                init,
+                Expression::Identifier(_) | Expression::StaticMemberExpression(_)
+            ) {
+                self.unresolved = true;
+            }
+        }
+    }
+}
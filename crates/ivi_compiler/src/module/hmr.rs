@@ -0,0 +1,179 @@
+use std::{
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::SPAN;
+use rustc_hash::{FxHashSet, FxHasher};
+
+use crate::context::TraverseCtx;
+
+// Stable id of a template or component, it doesn't change when module is
+// edited unless templates or components are added or removed before it.
+pub fn hmr_id(filename: &Path, kind: &str, index: u32) -> String {
+    let mut hasher = FxHasher::default();
+    filename.hash(&mut hasher);
+    kind.hash(&mut hasher);
+    index.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+pub fn hmr_hash(source_text: &str) -> String {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+// callee("id", .., expr)
+pub fn hmr_register<'a>(
+    callee: Expression<'a>,
+    keys: &[&str],
+    expr: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let mut arguments = ArenaVec::with_capacity_in(keys.len() + 1, ctx);
+    for key in keys {
+        arguments.push(Argument::StringLiteral(StringLiteral::boxed(
+            SPAN,
+            Str::from_str_in(key, ctx),
+            None,
+            ctx,
+        )));
+    }
+    arguments.push(expr.into());
+    Expression::CallExpression(CallExpression::boxed(SPAN, callee, NONE, arguments, false, ctx))
+}
+
+// Returns `true` when module exports only components that are registered
+// with `_hmrC()`. Such modules can accept their own updates, other modules
+// should be updated by their importers.
+pub fn exports_only_components(body: &[Statement], hmr_component: &str) -> bool {
+    let is_component = |expr: &Expression| {
+        matches!(expr, Expression::CallExpression(call)
+            if matches!(&call.callee, Expression::Identifier(id) if id.name == hmr_component))
+    };
+    // Top-level bindings that are initialized with registered components.
+    let mut components = FxHashSet::default();
+    for stmt in body {
+        let decl = match stmt {
+            Statement::VariableDeclaration(decl) => decl,
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => decl,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        for d in &decl.declarations {
+            if let BindingPattern::BindingIdentifier(id) = &d.id
+                && d.init.as_ref().is_some_and(is_component)
+            {
+                components.insert(id.name.as_str());
+            }
+        }
+    }
+
+    let mut exports = 0;
+    for stmt in body {
+        match stmt {
+            // export const A = _hmrC("id", component(..));
+            // export { A, B as C };
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(declaration) = &export.declaration {
+                    let Declaration::VariableDeclaration(decl) = declaration else {
+                        return false;
+                    };
+                    for d in &decl.declarations {
+                        if !d
+                            .id
+                            .get_binding_identifier()
+                            .is_some_and(|id| components.contains(id.name.as_str()))
+                        {
+                            return false;
+                        }
+                        exports += 1;
+                    }
+                }
+                if export.source.is_some() && !export.specifiers.is_empty() {
+                    return false;
+                }
+                for spec in &export.specifiers {
+                    if !components.contains(spec.local.name().as_str()) {
+                        return false;
+                    }
+                    exports += 1;
+                }
+            }
+            // export default _hmrC("id", component(..));
+            // export default A;
+            Statement::ExportDefaultDeclaration(export) => {
+                let ok = match &export.declaration {
+                    ExportDefaultDeclarationKind::Identifier(id) => {
+                        components.contains(id.name.as_str())
+                    }
+                    declaration => declaration.as_expression().is_some_and(is_component),
+                };
+                if !ok {
+                    return false;
+                }
+                exports += 1;
+            }
+            Statement::ExportAllDeclaration(_) => {
+                return false;
+            }
+            _ => {}
+        }
+    }
+    exports > 0
+}
+
+// if (import.meta.hot) {
+//   import.meta.hot.accept();
+// }
+pub fn hot_accept_statement<'a>(ctx: &mut TraverseCtx<'a>) -> Statement<'a> {
+    let accept = Expression::CallExpression(CallExpression::boxed(
+        SPAN,
+        Expression::StaticMemberExpression(StaticMemberExpression::boxed(
+            SPAN,
+            import_meta_hot(ctx),
+            IdentifierName::new(SPAN, Str::from_str_in("accept", ctx), ctx),
+            false,
+            ctx,
+        )),
+        NONE,
+        ArenaVec::new_in(ctx),
+        false,
+        ctx,
+    ));
+    Statement::IfStatement(IfStatement::boxed(
+        SPAN,
+        import_meta_hot(ctx),
+        Statement::BlockStatement(BlockStatement::boxed(
+            SPAN,
+            ArenaVec::from_value_in(
+                Statement::ExpressionStatement(ExpressionStatement::boxed(SPAN, accept, ctx)),
+                ctx,
+            ),
+            ctx,
+        )),
+        None,
+        ctx,
+    ))
+}
+
+// import.meta.hot
+fn import_meta_hot<'a>(ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    Expression::StaticMemberExpression(StaticMemberExpression::boxed(
+        SPAN,
+        Expression::MetaProperty(MetaProperty::boxed(
+            SPAN,
+            IdentifierName::new(SPAN, Str::from_str_in("import", ctx), ctx),
+            IdentifierName::new(SPAN, Str::from_str_in("meta", ctx), ctx),
+            ctx,
+        )),
+        IdentifierName::new(SPAN, Str::from_str_in("hot", ctx), ctx),
+        false,
+        ctx,
+    ))
+}
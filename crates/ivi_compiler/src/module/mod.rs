@@ -1,52 +1,99 @@
-use std::collections::hash_map;
+use std::{collections::hash_map, path::Path};
 
 use oxc_allocator::{Address, Allocator, GetAddress, TakeIn, Vec as ArenaVec};
 use oxc_ast::ast::*;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::{Scoping, SymbolId};
-use oxc_traverse::{Traverse, traverse_mut};
+use oxc_span::{GetSpan, Span};
+use oxc_traverse::{Ancestor, Traverse, traverse_mut};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-    CompilerOptions,
+    CompilerOptions, HoistOptions, IviExport, WarningCode,
     context::{TraverseCtx, TraverseCtxState},
-    import::ImportSymbols,
-    oveo::oveo_intrinsic,
-    tpl::{TemplateKind, compile_template},
+    diagnostic::line_column,
+    import::{ImportKind, ImportSymbols},
+    optimizer::intrinsic,
+    tpl::{TemplateDevInfo, TemplateInfo, TemplateKind, compile_template},
 };
 
+mod bindings;
+mod hmr;
+
 pub fn compile_module<'a>(
     program: &mut Program<'a>,
     allocator: &'a Allocator,
     scoping: Scoping,
+    filename: &str,
     options: &CompilerOptions,
     strings: &mut FxHashSet<String>,
-) -> Vec<OxcDiagnostic> {
-    let mut t = ModuleCompiler::new(options, strings);
+) -> (Vec<OxcDiagnostic>, Option<Vec<TemplateInfo>>) {
+    let mut t = ModuleCompiler::new(Path::new(filename), program.source_text, options, strings);
+    t.resolve_bindings(program, &scoping);
     traverse_mut(&mut t, allocator, program, scoping, TraverseCtxState::default());
-    t.errors
+    (t.diagnostics, t.template_info)
 }
 
 struct ModuleCompiler<'a, 'ctx> {
+    filename: &'ctx Path,
+    source_text: &'a str,
     options: &'ctx CompilerOptions,
     strings: &'ctx mut FxHashSet<String>,
     ivi_module: FxHashMap<SymbolId, IviSymbol>,
+    // Exports of namespace imports, `None` when module has the same exports
+    // as ivi.
+    namespaces: Vec<Option<&'ctx FxHashMap<String, IviExport>>>,
+    // ivi module is imported with `require`.
+    require: bool,
+    // The first `await import()` of ivi module.
+    dynamic_import: Option<Span>,
+    // `html` and `svg` references that weren't used as tags.
+    template_references: FxHashMap<Span, &'static str>,
+    // Local aliases with spans of their initializers and aliases that are
+    // referenced by initializers, initializers aren't reported when aliases
+    // are used as tags.
+    template_aliases: FxHashMap<SymbolId, (Span, Option<SymbolId>)>,
+    // Aliases that were used as tags.
+    tag_aliases: Vec<SymbolId>,
     imports: ImportSymbols<'a>,
     statements: Vec<Address>,
     templates: FxHashMap<Address, Vec<Statement<'a>>>,
-    errors: Vec<OxcDiagnostic>,
+    // Template errors and warnings.
+    diagnostics: Vec<OxcDiagnostic>,
+    // Compiled templates, they are collected when `templates` option is
+    // enabled.
+    template_info: Option<Vec<TemplateInfo>>,
+    // The number of templates and components registered for HMR.
+    hmr_templates: u32,
+    hmr_components: u32,
 }
 
 impl<'a, 'ctx> ModuleCompiler<'a, 'ctx> {
-    pub fn new(options: &'ctx CompilerOptions, strings: &'ctx mut FxHashSet<String>) -> Self {
+    pub fn new(
+        filename: &'ctx Path,
+        source_text: &'a str,
+        options: &'ctx CompilerOptions,
+        strings: &'ctx mut FxHashSet<String>,
+    ) -> Self {
         Self {
+            filename,
+            source_text,
             options,
             strings,
             ivi_module: FxHashMap::default(),
+            namespaces: Vec::new(),
+            require: false,
+            dynamic_import: None,
+            template_references: FxHashMap::default(),
+            template_aliases: FxHashMap::default(),
+            tag_aliases: Vec::new(),
             imports: ImportSymbols::default(),
             statements: Vec::new(),
             templates: FxHashMap::default(),
-            errors: Vec::new(),
+            diagnostics: Vec::new(),
+            template_info: options.templates.then(Vec::new),
+            hmr_templates: 0,
+            hmr_components: 0,
         }
     }
 
@@ -61,13 +108,9 @@ impl<'a, 'ctx> ModuleCompiler<'a, 'ctx> {
                 }
             }
             Expression::StaticMemberExpression(expr) => {
-                if let Some(IviSymbol::Module) = self.resolve(&expr.object, scoping) {
-                    match expr.property.name.as_str() {
-                        "component" => Some(IviSymbol::Component),
-                        "html" => Some(IviSymbol::Html),
-                        "svg" => Some(IviSymbol::Svg),
-                        _ => None,
-                    }
+                if let Some(IviSymbol::Module(i)) = self.resolve(&expr.object, scoping) {
+                    resolve_export(self.namespaces[i], expr.property.name.as_str())
+                        .map(IviSymbol::from)
                 } else {
                     None
                 }
@@ -76,6 +119,22 @@ impl<'a, 'ctx> ModuleCompiler<'a, 'ctx> {
         }
     }
 
+    // Returns exports of an ivi module, `None` when module has the same
+    // exports as ivi.
+    fn resolve_module(&self, source: &str) -> Option<Option<&'ctx FxHashMap<String, IviExport>>> {
+        if let Some(m) = self.options.ivi_modules.iter().find(|m| m.specifier == source) {
+            Some(m.exports.as_ref())
+        } else {
+            self.options.re_exports.resolve(self.filename, source).map(Some)
+        }
+    }
+
+    // `html` and `svg` bindings that don't have any references.
+    fn is_unused_template(&self, symbol_id: SymbolId, scoping: &Scoping) -> bool {
+        matches!(self.ivi_module.get(&symbol_id), Some(IviSymbol::Html | IviSymbol::Svg))
+            && scoping.symbol_is_unused(symbol_id)
+    }
+
     fn add_template_decl(&mut self, address: Address, decl: Statement<'a>) {
         match self.templates.entry(address) {
             hash_map::Entry::Occupied(mut entry) => {
@@ -99,24 +158,80 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleCompiler<'a, '_> {
 
     fn exit_expression(&mut self, node: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         match node {
-            Expression::CallExpression(expr) if self.options.oveo => {
-                // hoist render functions
-                // component(() => hoist(() => { .. }));
-                if let Some(IviSymbol::Component) = self.resolve(&expr.callee, ctx.scoping()) {
-                    if let Some(Argument::ArrowFunctionExpression(expr)) = expr.arguments.get_mut(0)
-                    {
-                        if expr.expression {
-                            if let Some(Statement::ExpressionStatement(expr_stmt)) =
-                                &mut expr.body.statements.get_mut(0)
-                            {
-                                expr_stmt.expression = oveo_intrinsic(
-                                    expr_stmt.expression.take_in(ctx),
-                                    self.imports.hoist(ctx),
-                                    ctx,
-                                );
+            Expression::CallExpression(expr) => {
+                let symbol = self.resolve(&expr.callee, ctx.scoping());
+                let hoist = if self.options.optimizer.hoist {
+                    self.options.hoist
+                } else {
+                    HoistOptions::NONE
+                };
+                match symbol {
+                    // hoist render functions
+                    // component(() => hoist(() => { .. }));
+                    // component(() => { ..; return hoist(() => { .. }); });
+                    Some(IviSymbol::Component) if hoist.components => {
+                        match expr.arguments.get_mut(0) {
+                            Some(Argument::ArrowFunctionExpression(expr)) => {
+                                if expr.expression {
+                                    if let Some(Statement::ExpressionStatement(expr_stmt)) =
+                                        &mut expr.body.statements.get_mut(0)
+                                    {
+                                        expr_stmt.expression = intrinsic(
+                                            expr_stmt.expression.take_in(ctx),
+                                            self.imports.hoist(ctx),
+                                            ctx,
+                                        );
+                                    }
+                                } else {
+                                    hoist_returned_functions(
+                                        &mut expr.body.statements,
+                                        &mut self.imports,
+                                        ctx,
+                                    );
+                                }
+                            }
+                            Some(Argument::FunctionExpression(expr)) => {
+                                if let Some(body) = &mut expr.body {
+                                    hoist_returned_functions(
+                                        &mut body.statements,
+                                        &mut self.imports,
+                                        ctx,
+                                    );
+                                }
                             }
+                            _ => {}
+                        }
+                    }
+                    // List(entries, hoist(getKey), hoist(render))
+                    Some(IviSymbol::List) if hoist.list => {
+                        if let Some(args) = expr.arguments.get_mut(1..) {
+                            hoist_function_arguments(args, &mut self.imports, ctx);
                         }
                     }
+                    // useEffect(c, hoist(effect), hoist(areEqual))
+                    Some(IviSymbol::Effect) if hoist.effects => {
+                        if let Some(args) = expr.arguments.get_mut(1..) {
+                            hoist_function_arguments(args, &mut self.imports, ctx);
+                        }
+                    }
+                    _ => {}
+                }
+                // _hmrC("id", component(..))
+                //
+                // Components that are created in functions aren't registered,
+                // all of them would have the same id.
+                if self.options.hmr
+                    && matches!(symbol, Some(IviSymbol::Component))
+                    && is_top_level_component(ctx)
+                {
+                    let id = hmr::hmr_id(self.filename, "c", self.hmr_components);
+                    self.hmr_components += 1;
+                    *node = hmr::hmr_register(
+                        self.imports.hmr_component(ctx),
+                        &[&id],
+                        node.take_in(ctx),
+                        ctx,
+                    );
                 }
             }
             Expression::TaggedTemplateExpression(expr) => {
@@ -128,64 +243,145 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleCompiler<'a, '_> {
                             return;
                         }
                     };
+                    self.template_references.remove(&expr.tag.span());
+                    // Deleted references are used to remove unused imports.
+                    if let Expression::Identifier(id) = &expr.tag {
+                        if let Some(symbol_id) =
+                            ctx.scoping().get_reference(id.reference_id()).symbol_id()
+                        {
+                            self.tag_aliases.push(symbol_id);
+                        }
+                        ctx.delete_reference_for_identifier(id);
+                    }
+                    let source_hash = self
+                        .options
+                        .hmr
+                        .then(|| hmr::hmr_hash(expr.quasi.span.source_text(self.source_text)));
+                    let dev = self.options.dev.then(|| {
+                        TemplateDevInfo::new(
+                            expr,
+                            &self.filename.to_string_lossy(),
+                            self.source_text,
+                        )
+                    });
+                    let span = expr.span;
                     match compile_template(
                         &mut expr.quasi,
+                        span,
                         ctx,
                         kind,
                         &mut self.imports,
-                        self.options.oveo,
-                        self.options.dedupe_strings,
+                        self.options,
+                        dev.as_ref(),
                     ) {
                         Ok(result) => {
+                            if let Some(template_info) = &mut self.template_info {
+                                let (line, column) = line_column(self.source_text, span.start);
+                                template_info.push(TemplateInfo {
+                                    start: span.start,
+                                    end: span.end,
+                                    line,
+                                    column,
+                                    kind,
+                                    roots: result.roots,
+                                    blocks: result.blocks,
+                                });
+                            }
                             for s in result.strings {
                                 self.strings.insert(s);
                             }
                             let address = self.statements[0];
-                            for decl in result.decl {
+                            for mut decl in result.decl {
+                                // const _TPL_ = _hmrT("id", "hash", descriptor);
+                                if let Some(source_hash) = &source_hash
+                                    && let Statement::VariableDeclaration(v) = &mut decl
+                                    && let Some(init) = &mut v.declarations[0].init
+                                {
+                                    let id = hmr::hmr_id(self.filename, "t", self.hmr_templates);
+                                    self.hmr_templates += 1;
+                                    *init = hmr::hmr_register(
+                                        self.imports.hmr_template(ctx),
+                                        &[&id, source_hash],
+                                        init.take_in(ctx),
+                                        ctx,
+                                    );
+                                }
                                 self.add_template_decl(address, decl);
                             }
+                            self.diagnostics.extend(result.warnings);
                             *node = result.expr;
                         }
                         Err(error) => {
-                            self.errors.push(error);
+                            self.diagnostics.push(error);
                         }
                     }
                 }
             }
+            // `html` and `svg` references are reported in `exit_program` unless
+            // they are used as tags or aliases.
+            Expression::Identifier(_) | Expression::StaticMemberExpression(_) => {
+                match self.resolve(node, ctx.scoping()) {
+                    Some(IviSymbol::Html) => {
+                        self.template_references.insert(node.span(), "html");
+                    }
+                    Some(IviSymbol::Svg) => {
+                        self.template_references.insert(node.span(), "svg");
+                    }
+                    _ => {}
+                }
+            }
             _ => {}
         }
     }
 
-    fn exit_import_declaration(
+    fn exit_variable_declarator(
         &mut self,
-        node: &mut ImportDeclaration<'a>,
-        _ctx: &mut TraverseCtx<'a>,
+        node: &mut VariableDeclarator<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
-        // Resolve ivi module
-        if let Some(specifiers) = &node.specifiers {
-            let source = &node.source;
-            if source.value != "ivi" {
-                return;
-            }
-
-            for spec in specifiers {
-                match spec {
-                    // import { imported } from "source"
-                    // import { imported as local } from "source"
-                    ImportDeclarationSpecifier::ImportSpecifier(spec) => {
-                        let s = match spec.imported.name().as_str() {
-                            "component" => IviSymbol::Component,
-                            "html" => IviSymbol::Html,
-                            "svg" => IviSymbol::Svg,
-                            _ => {
-                                continue;
-                            }
-                        };
-                        self.ivi_module.insert(spec.local.symbol_id(), s);
+        // `html` and `svg` references that are used as aliases aren't reported
+        // when aliases are used as tags, aliases are resolved before traversal.
+        // const h = html;
+        //
+        // Exported aliases are reported.
+        // export const h = html;
+        if let BindingPattern::BindingIdentifier(id) = &node.id
+            && let Some(init) = &node.init
+            && matches!(
+                self.ivi_module.get(&id.symbol_id()),
+                Some(IviSymbol::Html | IviSymbol::Svg)
+            )
+            && !matches!(ctx.ancestor(1), Ancestor::ExportNamedDeclarationDeclaration(_))
+        {
+            let alias = match init {
+                Expression::Identifier(r) => {
+                    ctx.scoping().get_reference(r.reference_id()).symbol_id()
+                }
+                _ => None,
+            };
+            self.template_aliases.insert(id.symbol_id(), (init.span(), alias));
+        }
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        node: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // export { html as h };
+        if node.source.is_some() {
+            return;
+        }
+        for spec in &node.specifiers {
+            if let ModuleExportName::IdentifierReference(id) = &spec.local
+                && let Some(symbol_id) = ctx.scoping().get_reference(id.reference_id()).symbol_id()
+            {
+                match self.ivi_module.get(&symbol_id) {
+                    Some(IviSymbol::Html) => {
+                        self.template_references.insert(id.span, "html");
                     }
-                    // import * as local from "source"
-                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
-                        self.ivi_module.insert(spec.local.symbol_id(), IviSymbol::Module);
+                    Some(IviSymbol::Svg) => {
+                        self.template_references.insert(id.span, "svg");
                     }
                     _ => {}
                 }
@@ -193,8 +389,127 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleCompiler<'a, '_> {
         }
     }
 
+    fn exit_statements(
+        &mut self,
+        node: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        // Remove `html` and `svg` bindings from ivi module requests that don't
+        // have any references after templates were precompiled.
+        // const { html } = await import("ivi");
+        node.retain_mut(|stmt| {
+            let Statement::VariableDeclaration(decl) = stmt else {
+                return true;
+            };
+            let len = decl.declarations.len();
+            decl.declarations.retain_mut(|d| {
+                let ivi = d
+                    .init
+                    .as_ref()
+                    .and_then(|init| module_request(init, ctx.scoping()))
+                    .is_some_and(|(source, _)| self.resolve_module(source).is_some());
+                if !ivi {
+                    return true;
+                }
+                let BindingPattern::ObjectPattern(pattern) = &mut d.id else {
+                    return true;
+                };
+                let len = pattern.properties.len();
+                pattern.properties.retain(|p| {
+                    !matches!(&p.value, BindingPattern::BindingIdentifier(id)
+                        if self.is_unused_template(id.symbol_id(), ctx.scoping()))
+                });
+                pattern.properties.len() == len
+                    || !pattern.properties.is_empty()
+                    || pattern.rest.is_some()
+            });
+            decl.declarations.len() == len || !decl.declarations.is_empty()
+        });
+    }
+
     fn exit_program(&mut self, node: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        let imports = self.imports.create_import_statements(ctx);
+        // const t = h;
+        // const h = html;
+        while let Some(symbol_id) = self.tag_aliases.pop() {
+            if let Some((span, alias)) = self.template_aliases.remove(&symbol_id) {
+                self.template_references.remove(&span);
+                self.tag_aliases.extend(alias);
+            }
+        }
+        let mut references: Vec<_> = self.template_references.drain().collect();
+        references.sort_unstable_by_key(|(span, _)| span.start);
+        for (span, name) in references {
+            self.diagnostics.push(
+                WarningCode::UnsupportedTemplate
+                    .warn(format!("'{name}' template can't be precompiled"))
+                    .with_help(format!(
+                        "'{name}' should be used as a tag of tagged templates, templates that \
+                         aren't precompiled are parsed at runtime"
+                    ))
+                    .with_label(span),
+            );
+        }
+
+        let static_import = node.body.iter().any(|stmt| {
+            matches!(stmt, Statement::ImportDeclaration(decl)
+                if self.resolve_module(decl.source.value.as_str()).is_some())
+        });
+        // Remove `html` and `svg` imports that don't have any references after
+        // templates were precompiled.
+        node.body.retain_mut(|stmt| {
+            let Statement::ImportDeclaration(decl) = stmt else {
+                return true;
+            };
+            if self.resolve_module(decl.source.value.as_str()).is_none() {
+                return true;
+            }
+            let Some(specifiers) = &mut decl.specifiers else {
+                return true;
+            };
+            if specifiers.is_empty() {
+                return true;
+            }
+            specifiers.retain(|spec| match spec {
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    !self.is_unused_template(spec.local.symbol_id(), ctx.scoping())
+                }
+                _ => true,
+            });
+            !specifiers.is_empty()
+        });
+
+        // Helpers are imported with `require` when ivi is required from a
+        // CommonJS module.
+        let kind = if (self.require || node.source_type.is_commonjs())
+            && !node.body.iter().any(|stmt| stmt.is_module_declaration())
+        {
+            ImportKind::Require
+        } else {
+            ImportKind::Static
+        };
+        let imports = self.imports.create_import_statements(
+            &self.options.runtime_module,
+            &self.options.optimizer.module,
+            kind,
+            ctx,
+        );
+        // Helpers can't be imported with `await import()`, it would turn
+        // modules into top-level await modules.
+        if !imports.is_empty()
+            && !static_import
+            && !self.require
+            && let Some(span) = self.dynamic_import
+        {
+            self.diagnostics.push(
+                WarningCode::DynamicImport
+                    .warn("ivi module is imported dynamically, but template helpers are imported statically")
+                    .with_help(
+                        "Template helpers are imported with static imports, so ivi module isn't \
+                         loaded lazily. Move templates into a module that is imported dynamically",
+                    )
+                    .with_label(span),
+            );
+        }
         if !imports.is_empty() {
             let index = node
                 .body
@@ -205,6 +520,15 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleCompiler<'a, '_> {
             node.body.splice(index..index, imports);
         }
 
+        // Modules that export anything other than components are updated by
+        // their importers.
+        if kind != ImportKind::Require
+            && let Some(hmr_component) = self.imports.hmr_component_name()
+            && hmr::exports_only_components(&node.body, hmr_component)
+        {
+            node.body.push(hmr::hot_accept_statement(ctx));
+        }
+
         if !self.templates.is_empty() {
             let statements = &mut node.body;
             let mut new_statements =
@@ -222,10 +546,133 @@ impl<'a> Traverse<'a, TraverseCtxState<'a>> for ModuleCompiler<'a, '_> {
     }
 }
 
+// Wraps inline function arguments with `hoist()`.
+fn hoist_function_arguments<'a>(
+    arguments: &mut [Argument<'a>],
+    imports: &mut ImportSymbols<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    for arg in arguments {
+        if matches!(arg, Argument::ArrowFunctionExpression(_) | Argument::FunctionExpression(_)) {
+            let expr = arg.to_expression_mut();
+            *expr = intrinsic(expr.take_in(ctx), imports.hoist(ctx), ctx);
+        }
+    }
+}
+
+// Wraps functions returned from a function body with `hoist()`, nested
+// functions are ignored.
+fn hoist_returned_functions<'a>(
+    statements: &mut [Statement<'a>],
+    imports: &mut ImportSymbols<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) {
+    for stmt in statements {
+        match stmt {
+            Statement::ReturnStatement(stmt) => {
+                if let Some(
+                    arg @ (Expression::ArrowFunctionExpression(_)
+                    | Expression::FunctionExpression(_)),
+                ) = &mut stmt.argument
+                {
+                    *arg = intrinsic(arg.take_in(ctx), imports.hoist(ctx), ctx);
+                }
+            }
+            Statement::BlockStatement(stmt) => {
+                hoist_returned_functions(&mut stmt.body, imports, ctx);
+            }
+            Statement::IfStatement(stmt) => {
+                hoist_returned_functions(std::slice::from_mut(&mut stmt.consequent), imports, ctx);
+                if let Some(alternate) = &mut stmt.alternate {
+                    hoist_returned_functions(std::slice::from_mut(alternate), imports, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// const A = component(..);
+// export default component(..);
+// exports.A = component(..);
+fn is_top_level_component(ctx: &TraverseCtx) -> bool {
+    if ctx.current_scope_id() != ctx.scoping().root_scope_id() {
+        return false;
+    }
+    match ctx.parent() {
+        Ancestor::VariableDeclaratorInit(d) => *d.kind() == VariableDeclarationKind::Const,
+        Ancestor::ExportDefaultDeclarationDeclaration(_) => true,
+        Ancestor::AssignmentExpressionRight(_) => {
+            matches!(ctx.ancestor(1), Ancestor::ExpressionStatementExpression(_))
+        }
+        _ => false,
+    }
+}
+
+// require("source") => ("source", true)
+// await import("source") => ("source", false)
+fn module_request<'a>(expr: &'a Expression, scoping: &Scoping) -> Option<(&'a str, bool)> {
+    match expr {
+        Expression::CallExpression(call) => {
+            if let Expression::Identifier(id) = &call.callee
+                && id.name == "require"
+                && scoping.get_reference(id.reference_id()).symbol_id().is_none()
+                && let [Argument::StringLiteral(source)] = call.arguments.as_slice()
+            {
+                Some((source.value.as_str(), true))
+            } else {
+                None
+            }
+        }
+        Expression::AwaitExpression(expr) => {
+            if let Expression::ImportExpression(expr) = &expr.argument
+                && let Expression::StringLiteral(source) = &expr.source
+            {
+                Some((source.value.as_str(), false))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_constant_binding(
+    id: &BindingIdentifier,
+    kind: VariableDeclarationKind,
+    scoping: &Scoping,
+) -> bool {
+    kind.is_const() || !scoping.symbol_is_mutated(id.symbol_id())
+}
+
+fn resolve_export(exports: Option<&FxHashMap<String, IviExport>>, name: &str) -> Option<IviExport> {
+    match exports {
+        Some(exports) => exports.get(name).copied(),
+        None => IviExport::from_name(name),
+    }
+}
+
 #[derive(Clone, Copy)]
 enum IviSymbol {
-    Module,
+    // Index in the namespace exports list.
+    Module(usize),
     Component,
     Html,
     Svg,
+    List,
+    Effect,
+}
+
+impl From<IviExport> for IviSymbol {
+    fn from(value: IviExport) -> Self {
+        match value {
+            IviExport::Component => Self::Component,
+            IviExport::Html => Self::Html,
+            IviExport::Svg => Self::Svg,
+            IviExport::List => Self::List,
+            IviExport::UseEffect
+            | IviExport::UseAnimationFrameEffect
+            | IviExport::UseIdleEffect => Self::Effect,
+        }
+    }
 }
@@ -7,8 +7,8 @@ use oxc_span::SPAN;
 
 use crate::context::TraverseCtx;
 
-// annotation(expr)
-pub fn oveo_intrinsic<'a>(
+// intrinsic(expr)
+pub fn intrinsic<'a>(
     expr: Expression<'a>,
     callee: Expression<'a>,
     ctx: &mut TraverseCtx<'a>,
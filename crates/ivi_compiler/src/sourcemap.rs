@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use oxc_sourcemap::{SourceMap, Token};
+use rustc_hash::FxHashMap;
+
+// Composes `map` (output -> ivi input) with `input_map` (ivi input -> original
+// sources), so that the resulting map points to the original sources.
+//
+// Tokens that don't have a corresponding position in the input map are kept
+// without original positions, otherwise generated code would be attributed to
+// the previous token.
+pub fn compose_source_maps(map: &SourceMap, input_map: &SourceMap) -> SourceMap<'static> {
+    let lookup_table = input_map.generate_lookup_table();
+    let mut sources = Vec::new();
+    let mut source_contents = Vec::new();
+    let mut source_ids = FxHashMap::default();
+    let mut names = Vec::new();
+    let mut name_ids = FxHashMap::default();
+    let mut tokens = Vec::with_capacity(map.get_tokens().size_hint().0);
+
+    for token in map.get_tokens() {
+        let original = input_map
+            .lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+            // Input tokens from other lines don't map this position.
+            .filter(|original| original.get_dst_line() == token.get_src_line());
+        let Some((original, input_source_id)) =
+            original.and_then(|original| Some((original, original.get_source_id()?)))
+        else {
+            tokens.push(Token::new(token.get_dst_line(), token.get_dst_col(), 0, 0, None, None));
+            continue;
+        };
+        let source_id = *source_ids.entry(input_source_id).or_insert_with(|| {
+            sources.push(Cow::Owned(
+                input_map.get_source(input_source_id).unwrap_or_default().to_string(),
+            ));
+            source_contents.push(
+                input_map.get_source_content(input_source_id).map(|s| Cow::Owned(s.to_string())),
+            );
+            sources.len() as u32 - 1
+        });
+        let name = original
+            .get_name_id()
+            .and_then(|id| input_map.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        let name_id = name.map(|name| {
+            *name_ids.entry(name).or_insert_with(|| {
+                names.push(Cow::Owned(name.to_string()));
+                names.len() as u32 - 1
+            })
+        });
+        tokens.push(Token::new(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original.get_src_line(),
+            original.get_src_col(),
+            Some(source_id),
+            name_id,
+        ));
+    }
+
+    SourceMap::new(
+        map.get_file().map(|file| Cow::Owned(file.to_string())),
+        names,
+        input_map.get_source_root().map(|root| Cow::Owned(root.to_string())),
+        sources,
+        source_contents,
+        tokens.into_boxed_slice(),
+        None,
+    )
+}
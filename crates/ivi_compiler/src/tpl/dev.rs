@@ -0,0 +1,96 @@
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{GetSpan, SPAN};
+
+use crate::{context::TraverseCtx, diagnostic::line_column, import::ImportSymbols};
+
+// Template source information that is attached to template descriptors in
+// dev mode.
+pub struct TemplateDevInfo {
+    file: String,
+    line: u32,
+    column: u32,
+    template: String,
+    exprs: Vec<String>,
+}
+
+impl TemplateDevInfo {
+    pub fn new(expr: &TaggedTemplateExpression, file: &str, source_text: &str) -> Self {
+        let (line, column) = line_column(source_text, expr.span.start);
+        // Template text without backticks.
+        let quasi = expr.quasi.span;
+        let template = source_text[quasi.start as usize + 1..quasi.end as usize - 1].to_string();
+        let exprs = expr
+            .quasi
+            .expressions
+            .iter()
+            .map(|e| e.span().source_text(source_text).to_string())
+            .collect();
+        Self { file: file.to_string(), line, column, template, exprs }
+    }
+}
+
+// _dev(descriptor, { file, line, column, template, exprs })
+pub fn dev_descriptor<'a>(
+    descriptor: Expression<'a>,
+    info: &TemplateDevInfo,
+    ctx: &mut TraverseCtx<'a>,
+    imports: &mut ImportSymbols<'a>,
+) -> Expression<'a> {
+    let pure = matches!(&descriptor, Expression::CallExpression(call) if call.pure);
+    let mut properties = ArenaVec::with_capacity_in(5, ctx);
+    properties.push(property("file", string(&info.file, ctx), ctx));
+    properties.push(property("line", number(info.line, ctx), ctx));
+    properties.push(property("column", number(info.column, ctx), ctx));
+    properties.push(property("template", string(&info.template, ctx), ctx));
+    let mut exprs = ArenaVec::with_capacity_in(info.exprs.len(), ctx);
+    for e in &info.exprs {
+        exprs.push(string(e, ctx).into());
+    }
+    properties.push(property(
+        "exprs",
+        Expression::ArrayExpression(ArrayExpression::boxed(SPAN, exprs, ctx)),
+        ctx,
+    ));
+    let info = Expression::ObjectExpression(ObjectExpression::boxed(SPAN, properties, ctx));
+    Expression::CallExpression(CallExpression::boxed_with_pure(
+        SPAN,
+        imports.dev(ctx),
+        NONE,
+        ArenaVec::from_array_in([descriptor.into(), info.into()], ctx),
+        false,
+        pure,
+        ctx,
+    ))
+}
+
+fn property<'a>(
+    key: &'static str,
+    value: Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    ObjectPropertyKind::ObjectProperty(ObjectProperty::boxed(
+        SPAN,
+        PropertyKind::Init,
+        PropertyKey::StaticIdentifier(IdentifierName::boxed(SPAN, Str::from_str_in(key, ctx), ctx)),
+        value,
+        false,
+        false,
+        false,
+        ctx,
+    ))
+}
+
+fn string<'a>(s: &str, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    Expression::StringLiteral(StringLiteral::boxed(SPAN, Str::from_str_in(s, ctx), None, ctx))
+}
+
+fn number<'a>(n: u32, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+    Expression::NumericLiteral(NumericLiteral::boxed(
+        SPAN,
+        n as f64,
+        None,
+        NumberBase::Decimal,
+        ctx,
+    ))
+}
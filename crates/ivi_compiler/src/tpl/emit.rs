@@ -7,12 +7,13 @@ use oxc_ast::{
 use oxc_span::SPAN;
 
 use crate::{
+    HoistOptions,
     context::TraverseCtx,
     import::ImportSymbols,
-    oveo::oveo_intrinsic,
+    optimizer::intrinsic,
     tpl::{
         TemplateKind,
-        opcodes::{child_op, common_prop_type, prop_op, state_op, template_flags},
+        opcodes::{child_op, common_prop_type, event_modifier, prop_op, state_op, template_flags},
         parser::{
             TElement, TNode, TNodeKind, TProperty, TPropertyAttributeValue, TPropertyStyleValue,
         },
@@ -41,13 +42,13 @@ pub fn emit_root_element<'a>(
     expressions: &mut ArenaVec<'a, Expression<'a>>,
     ctx: &mut TraverseCtx<'a>,
     imports: &mut ImportSymbols<'a>,
-    oveo: bool,
+    hoist: HoistOptions,
 ) -> TemplateNode<'a> {
     match &node.kind {
         TNodeKind::Element(e) => {
             let statics = emit_static_template(e, expressions, &mut ctx.ast);
 
-            let expr_map = create_expr_map(e, ctx, expressions, imports, oveo);
+            let expr_map = create_expr_map(e, ctx, expressions, imports, hoist);
 
             let state_op_codes = emit_state_op_codes(e);
             let (props_op_codes, strings) = emit_props_op_codes(node, &expr_map);
@@ -102,10 +103,10 @@ fn create_expr_map<'a>(
     ctx: &mut TraverseCtx<'a>,
     expressions: &mut ArenaVec<'a, Expression<'a>>,
     imports: &mut ImportSymbols<'a>,
-    oveo: bool,
+    hoist: HoistOptions,
 ) -> IndexSet<usize> {
     let mut map = IndexSet::default();
-    _create_expr_map(&mut map, root, ctx, expressions, imports, oveo);
+    _create_expr_map(&mut map, root, ctx, expressions, imports, hoist);
     map
 }
 
@@ -115,7 +116,7 @@ fn _create_expr_map<'a>(
     ctx: &mut TraverseCtx<'a>,
     expressions: &mut ArenaVec<'a, Expression<'a>>,
     imports: &mut ImportSymbols<'a>,
-    oveo: bool,
+    hoist: HoistOptions,
 ) {
     for p in &node.properties {
         match p {
@@ -139,13 +140,21 @@ fn _create_expr_map<'a>(
             }
             TProperty::Event(p) => {
                 let i = p.value.inner();
-                if oveo {
+                if hoist.events && p.hoist {
                     expressions[i] =
-                        oveo_intrinsic(expressions[i].take_in(ctx), imports.hoist(ctx), ctx);
+                        intrinsic(expressions[i].take_in(ctx), imports.hoist(ctx), ctx);
                 }
                 map.insert(i);
             }
             TProperty::Directive(p) => {
+                let i = p.inner();
+                if hoist.directives {
+                    expressions[i] =
+                        intrinsic(expressions[i].take_in(ctx), imports.hoist(ctx), ctx);
+                }
+                map.insert(i);
+            }
+            TProperty::Ref(p) => {
                 map.insert(p.inner());
             }
         }
@@ -154,7 +163,7 @@ fn _create_expr_map<'a>(
     for c in &node.children {
         match &c.kind {
             TNodeKind::Element(e) => {
-                _create_expr_map(map, e, ctx, expressions, imports, oveo);
+                _create_expr_map(map, e, ctx, expressions, imports, hoist);
             }
             TNodeKind::Expr(e) => {
                 map.insert(e.index.inner());
@@ -439,9 +448,11 @@ fn _emit_props_op_codes(
                     }
                     TProperty::Event(p) => {
                         if let Some(i) = expr_map.get_index_of(&p.value.inner()) {
+                            let data = (string_index(strings, &p.key) << event_modifier::KEY_SHIFT)
+                                | p.modifiers;
                             op_codes.push(
                                 prop_op::EVENT
-                                    | (string_index(strings, &p.key) << prop_op::DATA_SHIFT)
+                                    | (data << prop_op::DATA_SHIFT)
                                     | ((i as u32) << prop_op::INPUT_SHIFT),
                             );
                         }
@@ -452,6 +463,15 @@ fn _emit_props_op_codes(
                                 .push(prop_op::DIRECTIVE | ((i as u32) << prop_op::INPUT_SHIFT));
                         }
                     }
+                    TProperty::Ref(p) => {
+                        if let Some(i) = expr_map.get_index_of(&p.inner()) {
+                            op_codes.push(
+                                prop_op::COMMON
+                                    | (common_prop_type::REF << prop_op::DATA_SHIFT)
+                                    | ((i as u32) << prop_op::INPUT_SHIFT),
+                            );
+                        }
+                    }
                 }
             }
         }
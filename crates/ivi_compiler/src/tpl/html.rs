@@ -19,3 +19,56 @@ pub fn is_html_void_element(tag: &str) -> bool {
             | "wbr"
     )
 }
+
+// Boolean attributes that are reflected by DOM properties: `(attribute,
+// property, tags)`, `None` tags means that attribute applies to all elements.
+//
+// Should be kept in sync with `HTML_BOOLEAN_ATTRIBUTES` in
+// `packages/ivi/src/template/shared.ts`, `tests/compiler/boolean-attributes`
+// checks that both tables are the same.
+const HTML_BOOLEAN_ATTRIBUTES: &[(&str, &str, Option<&[&str]>)] = &[
+    ("hidden", "hidden", None),
+    ("inert", "inert", None),
+    ("autofocus", "autofocus", None),
+    (
+        "disabled",
+        "disabled",
+        Some(&["button", "fieldset", "input", "optgroup", "option", "select", "textarea"]),
+    ),
+    ("readonly", "readOnly", Some(&["input", "textarea"])),
+    ("required", "required", Some(&["input", "select", "textarea"])),
+    ("multiple", "multiple", Some(&["input", "select"])),
+    ("formnovalidate", "formNoValidate", Some(&["button", "input"])),
+    ("novalidate", "noValidate", Some(&["form"])),
+    ("open", "open", Some(&["details", "dialog"])),
+    ("reversed", "reversed", Some(&["ol"])),
+    ("ismap", "isMap", Some(&["img"])),
+    ("allowfullscreen", "allowFullscreen", Some(&["iframe"])),
+    ("default", "default", Some(&["track"])),
+    ("async", "async", Some(&["script"])),
+    ("defer", "defer", Some(&["script"])),
+    ("nomodule", "noModule", Some(&["script"])),
+    ("autoplay", "autoplay", Some(&["audio", "video"])),
+    ("controls", "controls", Some(&["audio", "video"])),
+    ("loop", "loop", Some(&["audio", "video"])),
+    ("playsinline", "playsInline", Some(&["video"])),
+];
+
+// Returns a DOM property that reflects a boolean attribute, so that assigning
+// any falsy value removes the attribute.
+pub fn html_boolean_attribute_property(tag: &str, key: &str) -> Option<&'static str> {
+    let (_, property, tags) = HTML_BOOLEAN_ATTRIBUTES.iter().find(|(k, _, _)| *k == key)?;
+    match tags {
+        Some(tags) if !tags.contains(&tag) => None,
+        _ => Some(property),
+    }
+}
+
+// Returns `true` when an attribute only sets the initial state of a form
+// control and its value should be diffed against the DOM property instead.
+pub fn is_html_form_state_attribute(tag: &str, key: &str) -> bool {
+    matches!(
+        (tag, key),
+        ("input", "checked" | "value") | ("textarea" | "select", "value") | ("option", "selected")
+    )
+}
@@ -0,0 +1,89 @@
+use std::hash::{Hash, Hasher};
+
+use oxc_ast::ast::Expression;
+use rustc_hash::FxHasher;
+
+use crate::tpl::{TemplateKind, emit::TemplateBlock, opcodes::template_flags};
+
+// Compiled tagged template expression.
+#[derive(Debug, Clone)]
+pub struct TemplateInfo {
+    pub start: u32,
+    pub end: u32,
+    // 1-based line and column of the template start position.
+    pub line: u32,
+    pub column: u32,
+    pub kind: TemplateKind,
+    // The number of root nodes, including text and expression nodes.
+    pub roots: u32,
+    // Template blocks that are created from root elements and dynamic
+    // elements.
+    pub blocks: Vec<TemplateBlockInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateBlockInfo {
+    // Single-node template that is created with `_hE()` or `_sE()`, other
+    // templates are created from static HTML with `_hN()` or `_sN()`.
+    pub element: bool,
+    // Size of the static HTML, or the tag name of single-node templates.
+    pub statics_size: u32,
+    pub state_slots: u32,
+    pub child_slots: u32,
+    pub prop_op_codes: u32,
+    pub child_op_codes: u32,
+    pub state_op_codes: u32,
+    // Strings that are used by prop opcodes (attribute names, etc).
+    pub strings: Vec<String>,
+    // Hash of the template descriptor.
+    hash: u64,
+}
+
+// Blocks are equal when they are compiled into identical descriptors.
+impl PartialEq for TemplateBlockInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+            && self.element == other.element
+            && self.statics_size == other.statics_size
+            && self.strings == other.strings
+    }
+}
+
+impl Eq for TemplateBlockInfo {}
+
+impl Hash for TemplateBlockInfo {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl TemplateBlockInfo {
+    pub(crate) fn new(t: &TemplateBlock) -> Self {
+        let (element, statics) = match &t.statics {
+            Expression::StringLiteral(s) => (true, s.value.as_str()),
+            Expression::TemplateLiteral(l) => (false, l.quasis[0].value.raw.as_str()),
+            _ => (true, ""),
+        };
+        let mut hasher = FxHasher::default();
+        element.hash(&mut hasher);
+        statics.hash(&mut hasher);
+        t.flags.hash(&mut hasher);
+        t.props_op_codes.hash(&mut hasher);
+        t.child_op_codes.hash(&mut hasher);
+        t.state_op_codes.hash(&mut hasher);
+        for s in &t.strings {
+            s.hash(&mut hasher);
+        }
+        Self {
+            element,
+            statics_size: statics.len() as u32,
+            state_slots: t.flags & template_flags::MASK6,
+            child_slots: (t.flags >> template_flags::CHILDREN_SIZE_SHIFT) & template_flags::MASK6,
+            prop_op_codes: t.props_op_codes.len() as u32,
+            child_op_codes: t.child_op_codes.len() as u32,
+            state_op_codes: t.state_op_codes.len() as u32,
+            strings: t.strings.iter().cloned().collect(),
+            hash: hasher.finish(),
+        }
+    }
+}
@@ -3,50 +3,239 @@ use oxc_allocator::{TakeIn, Vec as ArenaVec};
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::SymbolFlags;
-use oxc_span::SPAN;
+use oxc_span::{GetSpan, GetSpanMut, SPAN, Span};
+use oxc_traverse::BoundIdentifier;
 
 use crate::{
-    context::TraverseCtx, import::ImportSymbols, oveo::oveo_intrinsic, tpl::emit::TemplateNode,
+    CompilerOptions, HoistOptions, Optimizer,
+    context::TraverseCtx,
+    import::ImportSymbols,
+    optimizer::intrinsic,
+    tpl::{
+        emit::{TemplateBlock, TemplateNode},
+        html::is_html_void_element,
+        parser::TNodeKind,
+    },
 };
 
+mod dev;
 mod emit;
-mod html;
+pub(crate) mod html;
+mod info;
 pub mod opcodes;
 mod parser;
 
+pub use dev::TemplateDevInfo;
+pub use info::{TemplateBlockInfo, TemplateInfo};
+
 pub struct CompiledTemplate<'a> {
     pub decl: Vec<Statement<'a>>,
     pub expr: Expression<'a>,
     pub strings: Vec<String>,
+    pub warnings: Vec<OxcDiagnostic>,
+    // The number of root nodes.
+    pub roots: u32,
+    pub blocks: Vec<TemplateBlockInfo>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TemplateKind {
     Html,
     Svg,
 }
 
+// `span` is the span of the tagged template expression, it is used as a span
+// for template descriptors and `_t()` calls, so that they are mapped to the
+// template in source maps. Generated identifiers don't have spans, otherwise
+// template source would be used as their source map names.
 pub fn compile_template<'a>(
     tpl: &mut TemplateLiteral<'a>,
+    span: Span,
     ctx: &mut TraverseCtx<'a>,
     kind: TemplateKind,
     imports: &mut ImportSymbols<'a>,
-    oveo: bool,
-    dedupe_strings: bool,
+    options: &CompilerOptions,
+    dev: Option<&TemplateDevInfo>,
 ) -> Result<CompiledTemplate<'a>, OxcDiagnostic> {
+    let optimizer = &options.optimizer;
+    let dedupe_strings = options.dedupe_strings;
+    let hoist = if optimizer.hoist { options.hoist } else { HoistOptions::NONE };
     let mut decl = Vec::new();
     let mut exprs = Vec::new();
     let mut strings = Vec::new();
-    let nodes = parser::parse_template(tpl, ctx.scoping())?;
+    let mut blocks = Vec::new();
+    let template = parser::parse_template(tpl, kind, ctx.scoping())?;
 
-    for n in &nodes {
-        let e = emit::emit_root_element(n, kind, &mut tpl.expressions, ctx, imports, oveo);
-        match e {
-            TemplateNode::Block(t) => {
-                let uid = ctx.generate_uid_in_root_scope("_TPL_", SymbolFlags::ConstVariable);
+    for _ in 0..template.reserved_exprs {
+        tpl.expressions.push(Expression::NullLiteral(NullLiteral::boxed(SPAN, ctx)));
+    }
 
+    for b in &template.bindings {
+        // [get, set] => get(), _b(set, key)
+        let binding_span = tpl.expressions[b.expr.inner()].span();
+        let Expression::ArrayExpression(mut binding) = tpl.expressions[b.expr.inner()].take_in(ctx)
+        else {
+            return Err(invalid_binding(binding_span));
+        };
+        if binding.elements.len() != 2 {
+            return Err(invalid_binding(binding_span));
+        }
+        let Some(set) = binding.elements.pop().and_then(into_expression) else {
+            return Err(invalid_binding(binding_span));
+        };
+        let Some(get) = binding.elements.pop().and_then(into_expression) else {
+            return Err(invalid_binding(binding_span));
+        };
+        tpl.expressions[b.value.inner()] = Expression::CallExpression(CallExpression::boxed(
+            SPAN,
+            get,
+            NONE,
+            ArenaVec::new_in(ctx),
+            false,
+            ctx,
+        ));
+        tpl.expressions[b.handler.inner()] = Expression::CallExpression(CallExpression::boxed(
+            SPAN,
+            imports.bind(ctx),
+            NONE,
+            ArenaVec::from_array_in(
+                [
+                    set.into(),
+                    Expression::StringLiteral(StringLiteral::boxed(
+                        SPAN,
+                        Str::from_str_in(&b.key, ctx),
+                        None,
+                        ctx,
+                    ))
+                    .into(),
+                ],
+                ctx,
+            ),
+            false,
+            ctx,
+        ));
+    }
+
+    // Dynamic elements are emitted before root nodes because their results are
+    // used as expressions in parent templates.
+    for d in &template.dynamic_elements {
+        let TemplateNode::Block(mut t) =
+            emit::emit_root_element(&d.node, kind, &mut tpl.expressions, ctx, imports, hoist)
+        else {
+            unreachable!("dynamic element should be emitted as a block");
+        };
+        blocks.push(TemplateBlockInfo::new(&t));
+        strings.extend(t.strings.iter().cloned());
+
+        // Simple nodes don't have static templates.
+        let statics = match t.statics.take_in(ctx) {
+            Expression::TemplateLiteral(l) => Some(l.quasis[0].value.raw.as_str().to_string()),
+            _ => None,
+        };
+        let has_children = match &d.node.kind {
+            TNodeKind::Element(e) => !e.children.is_empty(),
+            _ => false,
+        };
+        let mut tag = tpl.expressions[d.tag.inner()].take_in(ctx);
+        let descriptor = if is_static_tag(&tag) {
+            if kind == TemplateKind::Html
+                && has_children
+                && let Some(void) = find_void_tag(&tag)
+            {
+                return Err(OxcDiagnostic::error(format!(
+                    "Void element '{}' can't have children",
+                    void.value
+                ))
+                .with_label(void.span));
+            }
+            // c ? "h1" : "h2" => c ? _TPL_ : _TPL_2
+            let mut descriptors = StaticTagDescriptors {
+                kind,
+                optimizer,
+                dedupe_strings,
+                dev,
+                span,
+                statics,
+                block: &t,
+                descriptors: Vec::new(),
+            };
+            descriptors.replace(&mut tag, &mut decl, ctx, imports);
+            tag
+        } else {
+            // const _TPL_ = __IVI_TPL__(_Td(_hD([`<`, `>..</`, `>`]), ..opcodes));
+            //
+            // HTML elements without children are emitted without a closing tag,
+            // so that void elements could be created at runtime: [`<`, `>`].
+            let statics = if let Some(statics) = statics {
+                let mut parts = split_dynamic_statics(&statics).to_vec();
+                if kind == TemplateKind::Html && !has_children {
+                    parts[1] = parts[1].strip_suffix("</").unwrap_or(parts[1]);
+                    parts.pop();
+                }
+                let statics = Expression::CallExpression(CallExpression::boxed(
+                    SPAN,
+                    match kind {
+                        TemplateKind::Html => imports.html_dynamic_template(ctx),
+                        TemplateKind::Svg => imports.svg_dynamic_template(ctx),
+                    },
+                    NONE,
+                    ArenaVec::from_value_in(
+                        Expression::ArrayExpression(ArrayExpression::boxed(
+                            SPAN,
+                            ArenaVec::from_iter_in(
+                                parts.into_iter().map(|s| raw_template_literal(s, ctx).into()),
+                                ctx,
+                            ),
+                            ctx,
+                        ))
+                        .into(),
+                        ctx,
+                    ),
+                    false,
+                    ctx,
+                ));
+                optimize_statics(statics, optimizer, imports, ctx)
+            } else {
+                match kind {
+                    TemplateKind::Html => imports.html_element(ctx),
+                    TemplateKind::Svg => imports.svg_element(ctx),
+                }
+            };
+            let callee = imports.dynamic_template_descriptor(ctx);
+            let descriptor = template_descriptor(callee, statics, &t, ctx, imports, optimizer);
+            let uid = declare_template_descriptor(
+                descriptor,
+                dedupe_strings,
+                dev,
+                span,
+                &mut decl,
+                ctx,
+                imports,
+            );
+
+            // _TPL_(tag)
+            Expression::CallExpression(CallExpression::boxed(
+                span,
+                uid.create_read_expression(ctx),
+                NONE,
+                ArenaVec::from_value_in(tag.into(), ctx),
+                false,
+                ctx,
+            ))
+        };
+
+        tpl.expressions[d.index.inner()] =
+            create_from_template(descriptor, &t, span, &mut tpl.expressions, ctx, imports);
+    }
+
+    for n in &template.nodes {
+        let e = emit::emit_root_element(n, kind, &mut tpl.expressions, ctx, imports, hoist);
+        match e {
+            TemplateNode::Block(mut t) => {
+                blocks.push(TemplateBlockInfo::new(&t));
                 // const _TPL_ = __IVI_TPL__(_T(statics, ..opcodes));
-                let statics = if let Expression::StringLiteral(_) = t.statics {
+                let statics = t.statics.take_in(ctx);
+                let statics = if let Expression::StringLiteral(_) = statics {
                     Expression::CallExpression(CallExpression::boxed(
                         SPAN,
                         match kind {
@@ -54,7 +243,7 @@ pub fn compile_template<'a>(
                             TemplateKind::Svg => imports.svg_element(ctx),
                         },
                         NONE,
-                        ArenaVec::from_value_in(t.statics.into(), ctx),
+                        ArenaVec::from_value_in(statics.into(), ctx),
                         false,
                         ctx,
                     ))
@@ -66,112 +255,36 @@ pub fn compile_template<'a>(
                             TemplateKind::Svg => imports.svg_template(ctx),
                         },
                         NONE,
-                        ArenaVec::from_value_in(t.statics.into(), ctx),
+                        ArenaVec::from_value_in(statics.into(), ctx),
                         false,
                         ctx,
                     ))
                 };
-                let statics =
-                    if oveo { oveo_intrinsic(statics, imports.dedupe(ctx), ctx) } else { statics };
+                let statics = optimize_statics(statics, optimizer, imports, ctx);
+                strings.extend(t.strings.iter().cloned());
 
-                let mut arguments = ArenaVec::with_capacity_in(6, ctx);
-                arguments.push(statics.into());
-                arguments.push(
-                    Expression::NumericLiteral(NumericLiteral::boxed(
-                        SPAN,
-                        t.flags as f64,
-                        None,
-                        NumberBase::Decimal,
-                        ctx,
-                    ))
-                    .into(),
-                );
-                arguments
-                    .push(op_codes_into_expression(&t.props_op_codes, ctx, imports, oveo).into());
-                arguments
-                    .push(op_codes_into_expression(&t.child_op_codes, ctx, imports, oveo).into());
-                arguments
-                    .push(op_codes_into_expression(&t.state_op_codes, ctx, imports, oveo).into());
-                if !t.strings.is_empty() {
-                    arguments.push(strings_into_expression(&t.strings, ctx).into());
-                    strings.extend(t.strings);
-                }
-                let template_descriptor = Expression::CallExpression(CallExpression::boxed(
-                    SPAN,
-                    imports.template_descriptor(ctx),
-                    NONE,
-                    arguments,
-                    false,
-                    ctx,
-                ));
-                let v = Declaration::VariableDeclaration(VariableDeclaration::boxed(
-                    SPAN,
-                    VariableDeclarationKind::Const,
-                    ArenaVec::from_value_in(
-                        VariableDeclarator::new(
-                            SPAN,
-                            VariableDeclarationKind::Const,
-                            BindingPattern::BindingIdentifier(BindingIdentifier::boxed(
-                                SPAN, uid.name, ctx,
-                            )),
-                            NONE,
-                            Some(if dedupe_strings {
-                                Expression::CallExpression(CallExpression::boxed(
-                                    SPAN,
-                                    Expression::Identifier(IdentifierReference::boxed(
-                                        SPAN,
-                                        Str::from_str_in("__IVI_TPL__", ctx),
-                                        ctx,
-                                    )),
-                                    NONE,
-                                    ArenaVec::from_array_in([template_descriptor.into()], ctx),
-                                    false,
-                                    ctx,
-                                ))
-                            } else {
-                                template_descriptor
-                            }),
-                            false,
-                            ctx,
-                        ),
-                        ctx,
-                    ),
-                    false,
+                let callee = imports.template_descriptor(ctx);
+                let descriptor = template_descriptor(callee, statics, &t, ctx, imports, optimizer);
+                let uid = declare_template_descriptor(
+                    descriptor,
+                    dedupe_strings,
+                    dev,
+                    span,
+                    &mut decl,
                     ctx,
-                ));
-                decl.push(v.into());
+                    imports,
+                );
 
                 // _t(_TPL_, [expressions])
-                let call_expressions = if t.expressions.is_empty() {
-                    ArenaVec::from_array_in([uid.create_read_expression(ctx).into()], ctx)
-                } else {
-                    ArenaVec::from_array_in(
-                        [
-                            uid.create_read_expression(ctx).into(),
-                            Expression::ArrayExpression(ArrayExpression::boxed(
-                                SPAN,
-                                ArenaVec::from_iter_in(
-                                    t.expressions
-                                        .iter()
-                                        .map(|i| tpl.expressions[*i].take_in(ctx).into()),
-                                    ctx,
-                                ),
-                                ctx,
-                            ))
-                            .into(),
-                        ],
-                        ctx,
-                    )
-                };
-                let call = Expression::CallExpression(CallExpression::boxed(
-                    SPAN,
-                    imports.create_from_template(ctx),
-                    NONE,
-                    call_expressions,
-                    false,
+                let descriptor = uid.create_read_expression(ctx);
+                exprs.push(create_from_template(
+                    descriptor,
+                    &t,
+                    span,
+                    &mut tpl.expressions,
                     ctx,
+                    imports,
                 ));
-                exprs.push(call);
             }
             TemplateNode::Text(text) => {
                 exprs.push(Expression::StringLiteral(StringLiteral::boxed(
@@ -189,21 +302,335 @@ pub fn compile_template<'a>(
 
     let expr = if exprs.len() > 1 {
         Expression::ArrayExpression(ArrayExpression::boxed(
-            SPAN,
+            span,
             ArenaVec::from_iter_in(exprs.into_iter().map(|e| e.into()), ctx),
             ctx,
         ))
     } else {
         exprs.pop().unwrap()
     };
-    Ok(CompiledTemplate { decl, expr, strings })
+    Ok(CompiledTemplate {
+        decl,
+        expr,
+        strings,
+        warnings: template.warnings,
+        roots: template.nodes.len() as u32,
+        blocks,
+    })
+}
+
+// _T(statics, flags, props_op_codes, child_op_codes, state_op_codes, strings)
+fn template_descriptor<'a>(
+    callee: Expression<'a>,
+    statics: Expression<'a>,
+    t: &TemplateBlock<'a>,
+    ctx: &mut TraverseCtx<'a>,
+    imports: &mut ImportSymbols<'a>,
+    optimizer: &Optimizer,
+) -> Expression<'a> {
+    let mut arguments = ArenaVec::with_capacity_in(6, ctx);
+    arguments.push(statics.into());
+    arguments.push(
+        Expression::NumericLiteral(NumericLiteral::boxed(
+            SPAN,
+            t.flags as f64,
+            None,
+            NumberBase::Decimal,
+            ctx,
+        ))
+        .into(),
+    );
+    arguments
+        .push(op_codes_into_expression(&t.props_op_codes, ctx, imports, optimizer.dedupe).into());
+    arguments
+        .push(op_codes_into_expression(&t.child_op_codes, ctx, imports, optimizer.dedupe).into());
+    arguments
+        .push(op_codes_into_expression(&t.state_op_codes, ctx, imports, optimizer.dedupe).into());
+    if !t.strings.is_empty() {
+        arguments.push(strings_into_expression(&t.strings, ctx).into());
+    }
+    let mut call = CallExpression::boxed(SPAN, callee, NONE, arguments, false, ctx);
+    call.pure = optimizer.pure;
+    Expression::CallExpression(call)
+}
+
+// dedupe(/*#__PURE__*/ _hN(statics))
+fn optimize_statics<'a>(
+    mut statics: Expression<'a>,
+    optimizer: &Optimizer,
+    imports: &mut ImportSymbols<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    if let Expression::CallExpression(call) = &mut statics {
+        call.pure = optimizer.pure;
+    }
+    if optimizer.dedupe { intrinsic(statics, imports.dedupe(ctx), ctx) } else { statics }
+}
+
+// const _TPL_ = _dev(__IVI_TPL__(descriptor), info);
+fn declare_template_descriptor<'a>(
+    descriptor: Expression<'a>,
+    dedupe_strings: bool,
+    dev: Option<&TemplateDevInfo>,
+    span: Span,
+    decl: &mut Vec<Statement<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+    imports: &mut ImportSymbols<'a>,
+) -> BoundIdentifier<'a> {
+    let uid = ctx.generate_uid_in_root_scope("_TPL_", SymbolFlags::ConstVariable);
+    // `__IVI_TPL__()` is removed in `renderChunk`, pure descriptors should stay
+    // removable until then.
+    let pure = matches!(&descriptor, Expression::CallExpression(call) if call.pure);
+    let descriptor = if dedupe_strings {
+        Expression::CallExpression(CallExpression::boxed_with_pure(
+            SPAN,
+            Expression::Identifier(IdentifierReference::boxed(
+                SPAN,
+                Str::from_str_in("__IVI_TPL__", ctx),
+                ctx,
+            )),
+            NONE,
+            ArenaVec::from_array_in([descriptor.into()], ctx),
+            false,
+            pure,
+            ctx,
+        ))
+    } else {
+        descriptor
+    };
+    let mut descriptor = if let Some(dev) = dev {
+        dev::dev_descriptor(descriptor, dev, ctx, imports)
+    } else {
+        descriptor
+    };
+    // Declarations are hoisted, so they don't have spans. Otherwise comments
+    // before the template would be moved with them.
+    *descriptor.span_mut() = span;
+    let v = Declaration::VariableDeclaration(VariableDeclaration::boxed(
+        SPAN,
+        VariableDeclarationKind::Const,
+        ArenaVec::from_value_in(
+            VariableDeclarator::new(
+                span,
+                VariableDeclarationKind::Const,
+                BindingPattern::BindingIdentifier(BindingIdentifier::boxed(SPAN, uid.name, ctx)),
+                NONE,
+                Some(descriptor),
+                false,
+                ctx,
+            ),
+            ctx,
+        ),
+        false,
+        ctx,
+    ));
+    decl.push(v.into());
+    uid
+}
+
+// _t(descriptor, [expressions])
+fn create_from_template<'a>(
+    descriptor: Expression<'a>,
+    t: &TemplateBlock<'a>,
+    span: Span,
+    expressions: &mut ArenaVec<'a, Expression<'a>>,
+    ctx: &mut TraverseCtx<'a>,
+    imports: &mut ImportSymbols<'a>,
+) -> Expression<'a> {
+    let call_expressions = if t.expressions.is_empty() {
+        ArenaVec::from_array_in([descriptor.into()], ctx)
+    } else {
+        ArenaVec::from_array_in(
+            [
+                descriptor.into(),
+                Expression::ArrayExpression(ArrayExpression::boxed(
+                    SPAN,
+                    ArenaVec::from_iter_in(
+                        t.expressions.iter().map(|i| expressions[*i].take_in(ctx).into()),
+                        ctx,
+                    ),
+                    ctx,
+                ))
+                .into(),
+            ],
+            ctx,
+        )
+    };
+    Expression::CallExpression(CallExpression::boxed(
+        span,
+        imports.create_from_template(ctx),
+        NONE,
+        call_expressions,
+        false,
+        ctx,
+    ))
+}
+
+fn invalid_binding(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Binding should be an array literal with two elements: `[get, set]`")
+        .with_label(span)
+}
+
+fn into_expression(e: ArrayExpressionElement<'_>) -> Option<Expression<'_>> {
+    match e {
+        ArrayExpressionElement::SpreadElement(_) | ArrayExpressionElement::Elision(_) => None,
+        _ => Some(e.into_expression()),
+    }
+}
+
+// Tag expression with statically known alternatives: `"h1"`, `c ? "h1" : "h2"`.
+fn is_static_tag(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(s) => is_valid_tag_name(s.value.as_str()),
+        Expression::ConditionalExpression(expr) => {
+            is_static_tag(&expr.consequent) && is_static_tag(&expr.alternate)
+        }
+        Expression::ParenthesizedExpression(expr) => is_static_tag(&expr.expression),
+        _ => false,
+    }
+}
+
+fn find_void_tag<'e, 'a>(expr: &'e Expression<'a>) -> Option<&'e StringLiteral<'a>> {
+    match expr {
+        Expression::StringLiteral(s) if is_html_void_element(s.value.as_str()) => Some(s),
+        Expression::ConditionalExpression(expr) => {
+            find_void_tag(&expr.consequent).or_else(|| find_void_tag(&expr.alternate))
+        }
+        Expression::ParenthesizedExpression(expr) => find_void_tag(&expr.expression),
+        _ => None,
+    }
+}
+
+fn is_valid_tag_name(tag: &str) -> bool {
+    !tag.starts_with('-')
+        && !tag.is_empty()
+        && tag.chars().all(|c| matches!(c, '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '-'))
+}
+
+// Static templates of dynamic elements are emitted with an empty tag name:
+// `<>..</>` => [`<`, `>..</`, `>`]
+fn split_dynamic_statics(statics: &str) -> [&str; 3] {
+    let end = statics.len() - 1;
+    [&statics[..1], &statics[1..end], &statics[end..]]
+}
+
+fn raw_template_literal<'a>(raw: &str, ctx: &TraverseCtx<'a>) -> Expression<'a> {
+    Expression::TemplateLiteral(TemplateLiteral::boxed(
+        SPAN,
+        ArenaVec::from_value_in(
+            TemplateElement::new(
+                SPAN,
+                TemplateElementValue { raw: Str::from_str_in(raw, ctx), cooked: None },
+                true,
+                ctx,
+            ),
+            ctx,
+        ),
+        ArenaVec::new_in(ctx),
+        ctx,
+    ))
+}
+
+struct StaticTagDescriptors<'t, 'a> {
+    kind: TemplateKind,
+    optimizer: &'t Optimizer,
+    dedupe_strings: bool,
+    dev: Option<&'t TemplateDevInfo>,
+    span: Span,
+    statics: Option<String>,
+    block: &'t TemplateBlock<'a>,
+    descriptors: Vec<(String, BoundIdentifier<'a>)>,
+}
+
+impl<'a> StaticTagDescriptors<'_, 'a> {
+    // Replaces tag names with template descriptors.
+    fn replace(
+        &mut self,
+        expr: &mut Expression<'a>,
+        decl: &mut Vec<Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+        imports: &mut ImportSymbols<'a>,
+    ) {
+        match expr {
+            Expression::StringLiteral(s) => {
+                let tag = s.value.as_str().to_string();
+                if let Some((_, uid)) = self.descriptors.iter().find(|(t, _)| *t == tag) {
+                    *expr = uid.create_read_expression(ctx);
+                    return;
+                }
+                let statics = if let Some(statics) = &self.statics {
+                    let [start, middle, end] = split_dynamic_statics(statics);
+                    let statics = if self.kind == TemplateKind::Html && is_html_void_element(&tag) {
+                        format!("{start}{tag}{}", middle.strip_suffix("</").unwrap_or(middle))
+                    } else {
+                        format!("{start}{tag}{middle}{tag}{end}")
+                    };
+                    Expression::CallExpression(CallExpression::boxed(
+                        SPAN,
+                        match self.kind {
+                            TemplateKind::Html => imports.html_template(ctx),
+                            TemplateKind::Svg => imports.svg_template(ctx),
+                        },
+                        NONE,
+                        ArenaVec::from_value_in(raw_template_literal(&statics, ctx).into(), ctx),
+                        false,
+                        ctx,
+                    ))
+                } else {
+                    Expression::CallExpression(CallExpression::boxed(
+                        SPAN,
+                        match self.kind {
+                            TemplateKind::Html => imports.html_element(ctx),
+                            TemplateKind::Svg => imports.svg_element(ctx),
+                        },
+                        NONE,
+                        ArenaVec::from_value_in(
+                            Expression::StringLiteral(StringLiteral::boxed(
+                                SPAN,
+                                Str::from_str_in(&tag, ctx),
+                                None,
+                                ctx,
+                            ))
+                            .into(),
+                            ctx,
+                        ),
+                        false,
+                        ctx,
+                    ))
+                };
+                let statics = optimize_statics(statics, self.optimizer, imports, ctx);
+                let callee = imports.template_descriptor(ctx);
+                let descriptor =
+                    template_descriptor(callee, statics, self.block, ctx, imports, self.optimizer);
+                let uid = declare_template_descriptor(
+                    descriptor,
+                    self.dedupe_strings,
+                    self.dev,
+                    self.span,
+                    decl,
+                    ctx,
+                    imports,
+                );
+                *expr = uid.create_read_expression(ctx);
+                self.descriptors.push((tag, uid));
+            }
+            Expression::ConditionalExpression(e) => {
+                self.replace(&mut e.consequent, decl, ctx, imports);
+                self.replace(&mut e.alternate, decl, ctx, imports);
+            }
+            Expression::ParenthesizedExpression(e) => {
+                self.replace(&mut e.expression, decl, ctx, imports);
+            }
+            _ => {}
+        }
+    }
 }
 
 fn op_codes_into_expression<'a>(
     op_codes: &[u32],
     ctx: &mut TraverseCtx<'a>,
     imports: &mut ImportSymbols<'a>,
-    oveo: bool,
+    dedupe: bool,
 ) -> Expression<'a> {
     if op_codes.is_empty() {
         imports.empty_array(ctx)
@@ -225,7 +652,7 @@ fn op_codes_into_expression<'a>(
             ),
             ctx,
         ));
-        if oveo { oveo_intrinsic(expr, imports.dedupe(ctx), ctx) } else { expr }
+        if dedupe { intrinsic(expr, imports.dedupe(ctx), ctx) } else { expr }
     }
 }
 
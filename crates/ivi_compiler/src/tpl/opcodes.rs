@@ -1,6 +1,7 @@
 pub mod template_flags {
     pub const CHILDREN_SIZE_SHIFT: u32 = 6;
     pub const SVG: u32 = 1 << 12;
+    pub const MASK6: u32 = (1 << 6) - 1;
 }
 
 pub mod state_op {
@@ -13,6 +14,7 @@ pub mod common_prop_type {
     pub const CLASS_NAME: u32 = 0;
     pub const TEXT_CONTENT: u32 = 1;
     pub const INNER_HTML: u32 = 2;
+    pub const REF: u32 = 3;
 }
 
 pub mod prop_op {
@@ -29,6 +31,18 @@ pub mod prop_op {
     pub const DATA_SHIFT: u32 = 9;
 }
 
+// Event modifiers are stored in the lowest bits of the `prop_op::EVENT` data,
+// the event name index is stored after them.
+pub mod event_modifier {
+    pub const PASSIVE: u32 = 1;
+    pub const CAPTURE: u32 = 1 << 1;
+    pub const ONCE: u32 = 1 << 2;
+    pub const PREVENT: u32 = 1 << 3;
+    pub const STOP: u32 = 1 << 4;
+    pub const SELF: u32 = 1 << 5;
+    pub const KEY_SHIFT: u32 = 6;
+}
+
 pub mod child_op {
     pub const CHILD: u32 = 0b00;
     pub const SET_NEXT: u32 = 0b01;
@@ -1,8 +1,18 @@
-use oxc_ast::ast::{Expression, TemplateElement, TemplateLiteral};
+use oxc_ast::ast::{Expression, TemplateLiteral};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::Scoping;
-
-use crate::tpl::html::is_html_void_element;
+use oxc_span::GetSpan;
+
+use crate::{
+    WarningCode, ir,
+    tpl::{
+        TemplateKind,
+        html::{
+            html_boolean_attribute_property, is_html_form_state_attribute, is_html_void_element,
+        },
+        opcodes::event_modifier,
+    },
+};
 
 #[derive(Clone, Copy)]
 pub struct ExprIndex(usize);
@@ -41,22 +51,43 @@ impl TNode {
         Self { kind, flags: 0, state_index: 0, children_exprs: 0, props_exprs: 0 }
     }
 
-    fn text(text: &str) -> Self {
-        Self {
-            kind: TNodeKind::Text(TText { value: text.to_string() }),
-            flags: 0,
-            state_index: 0,
-            children_exprs: 0,
-            props_exprs: 0,
-        }
+    fn text(value: String) -> Self {
+        Self::new(TNodeKind::Text(TText { value }))
     }
+}
 
-    fn space_text() -> Self {
-        Self::text(" ")
-    }
+// <${tag}>..</>
+pub struct TDynamicElement {
+    pub index: ExprIndex,
+    pub tag: ExprIndex,
+    pub node: TNode,
+}
+
+// bind:value=${[get, set]}
+pub struct TBinding {
+    pub key: String,
+    pub expr: ExprIndex,
+    // get()
+    pub value: ExprIndex,
+    // _b(set, key)
+    pub handler: ExprIndex,
+}
+
+pub struct TTemplate {
+    pub nodes: Vec<TNode>,
+    // Elements with dynamic tag names are compiled into separate templates and
+    // are replaced with expressions that have indices after template expressions.
+    pub dynamic_elements: Vec<TDynamicElement>,
+    // Bindings are expanded into DOM value and event properties with
+    // expressions that have indices after template expressions.
+    pub bindings: Vec<TBinding>,
+    // The number of expressions that should be added after template expressions.
+    pub reserved_exprs: usize,
+    pub warnings: Vec<OxcDiagnostic>,
 }
 
 pub struct TElement {
+    // Dynamic elements have an empty tag.
     pub tag: String,
     pub properties: Vec<TProperty>,
     pub children: Vec<TNode>,
@@ -78,6 +109,7 @@ pub enum TProperty {
     Style(TPropertyStyle),
     Event(TPropertyEvent),
     Directive(ExprIndex),
+    Ref(ExprIndex),
 }
 
 pub struct TPropertyAttribute {
@@ -113,488 +145,257 @@ pub enum TPropertyStyleValue {
 
 pub struct TPropertyEvent {
     pub key: String,
+    pub modifiers: u32,
     pub value: ExprIndex,
+    // Binding handlers `_b(set, key)` are cached by the runtime and aren't
+    // hoisted.
+    pub hoist: bool,
 }
 
 pub fn parse_template<'a>(
     tpl: &'a TemplateLiteral,
+    kind: TemplateKind,
     scoping: &'a Scoping,
-) -> Result<Vec<TNode>, OxcDiagnostic> {
-    let mut parser = Parser::new(scoping, &tpl.quasis, &tpl.expressions);
-    let mut nodes = parser.parse_children_list()?;
+) -> Result<TTemplate, OxcDiagnostic> {
+    let template = ir::parse_template_literal(tpl).map_err(ir::ParseError::into_oxc)?;
+    let mut lowering = Lowering {
+        scoping,
+        kind,
+        expressions: &tpl.expressions,
+        dynamic_elements: Vec::new(),
+        bindings: Vec::new(),
+        reserved_exprs: 0,
+        warnings: template.warnings.into_iter().map(ir::ParseWarning::into_oxc).collect(),
+        foreign: false,
+    };
+    let mut nodes = lowering.lower_children(template.nodes)?;
     for n in &mut nodes {
         update_flags(n);
         assign_state_slots(n);
     }
-    Ok(nodes)
+    // Warnings are reported in the source order.
+    let mut warnings = lowering.warnings;
+    warnings.sort_by_key(|w| w.labels.as_ref().first().map(|l| l.offset()));
+    Ok(TTemplate {
+        nodes,
+        dynamic_elements: lowering.dynamic_elements,
+        bindings: lowering.bindings,
+        reserved_exprs: lowering.reserved_exprs,
+        warnings,
+    })
 }
 
-#[derive(Debug, Clone)]
-struct Parser<'a> {
+// Converts template IR into template nodes: expands bindings, lifts elements
+// with dynamic tag names and normalizes HTML attributes.
+struct Lowering<'a> {
     scoping: &'a Scoping,
-    quasis: &'a [TemplateElement<'a>],
+    kind: TemplateKind,
     expressions: &'a [Expression<'a>],
-    text: &'a str,
-    expr_cursor: usize,
+    dynamic_elements: Vec<TDynamicElement>,
+    bindings: Vec<TBinding>,
+    reserved_exprs: usize,
+    warnings: Vec<OxcDiagnostic>,
+    // Elements inside of `<svg>` and `<math>` elements in HTML templates.
+    foreign: bool,
 }
 
-impl<'a> Parser<'a> {
-    fn new(
-        scoping: &'a Scoping,
-        quasis: &'a [TemplateElement<'a>],
-        expressions: &'a [Expression<'a>],
-    ) -> Self {
-        Self {
-            scoping,
-            quasis,
-            expressions,
-            text: quasis[0].value.cooked.unwrap().as_str(),
-            expr_cursor: 0,
-        }
-    }
-
-    fn current_element(&self) -> &TemplateElement<'a> {
-        &self.quasis[self.expr_cursor]
-    }
-
-    fn is_end(&self) -> bool {
-        self.text.is_empty() && self.expr_cursor == self.expressions.len()
-    }
-
-    fn peek_char(&self) -> Option<char> {
-        self.text.chars().next()
-    }
-
-    fn peek_nth_char(&self, n: usize) -> Option<char> {
-        self.text.chars().nth(n)
-    }
-
-    fn try_consume_char(&mut self, expected: char) -> Option<char> {
-        if let Some(c) = self.text.chars().next()
-            && c == expected
-        {
-            self.text = &self.text[expected.len_utf8()..];
-            Some(expected)
-        } else {
-            None
-        }
-    }
-
-    fn consume_char(&mut self, expected: char) -> Result<(), OxcDiagnostic> {
-        if let Some(c) = self.text.chars().next()
-            && c == expected
-        {
-            self.text = &self.text[expected.len_utf8()..];
-            Ok(())
-        } else {
-            let parts = self.text.split_at(self.text.len().min(10));
-            Err(OxcDiagnostic::error(format!("Expected a '{expected}' char: {}", parts.0))
-                .with_label(self.current_element().span))
-        }
-    }
-
-    fn advance(&mut self, i: usize) {
-        self.text = &self.text[i..];
-    }
-
-    fn consume_expr(&mut self) -> Result<usize, OxcDiagnostic> {
-        if self.text.is_empty() && (self.expr_cursor) < self.expressions.len() {
-            let i = self.expr_cursor;
-            self.expr_cursor += 1;
-            self.text = self.current_element().value.cooked.unwrap().as_str();
-            Ok(i)
-        } else {
-            Err(OxcDiagnostic::error("Expected an expression")
-                .with_label(self.current_element().span))
-        }
-    }
-
-    fn consume_whitespace(&mut self) -> WhitespaceState {
-        let mut state = 0;
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            match c {
-                ' ' | '\t' => {
-                    state |= WhitespaceState::WHITESPACE;
-                }
-                '\n' | '\r' => {
-                    state |= WhitespaceState::WHITESPACE | WhitespaceState::CONTAINS_NEWLINE;
-                }
-                '\x0b' => {
-                    state |= WhitespaceState::WHITESPACE | WhitespaceState::CONTAINS_VERTICAL_TAB;
-                }
-                _ => {
-                    len = i;
-                    break;
-                }
-            }
-        }
-        if len == 0 {
-            WhitespaceState(0)
-        } else {
-            self.advance(len);
-            WhitespaceState(state)
-        }
+impl Lowering<'_> {
+    fn reserve_expr(&mut self) -> ExprIndex {
+        let index = ExprIndex(self.expressions.len() + self.reserved_exprs);
+        self.reserved_exprs += 1;
+        index
     }
 
-    fn parse_tag_name(&mut self) -> Result<String, OxcDiagnostic> {
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            match c {
-                '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' => {}
-                '-' => {
-                    if i == 0 {
-                        len = 0;
-                        break;
-                    }
-                }
-                _ => {
-                    len = i;
-                    break;
+    fn lower_children(&mut self, nodes: Vec<ir::Node>) -> Result<Vec<TNode>, OxcDiagnostic> {
+        let mut children = Vec::with_capacity(nodes.len());
+        for node in nodes {
+            match node {
+                ir::Node::Element(e) => children.push(self.lower_element(e)?),
+                ir::Node::Text(t) => children.push(TNode::text(t.value)),
+                ir::Node::Expr(e) => {
+                    children.push(TNode::new(TNodeKind::Expr(TExpr { index: ExprIndex(e.index) })))
                 }
+                ir::Node::Comment(_) => {}
             }
         }
-        if len > 0 {
-            let id = self.text[..len].to_string();
-            self.advance(len);
-            Ok(id)
-        } else {
-            let parts = self.text.split_at(self.text.len().min(10));
-            Err(OxcDiagnostic::error(format!("Invalid tag name: {}", parts.0))
-                .with_label(self.current_element().span))
-        }
+        Ok(children)
     }
 
-    fn parse_attribute_name(&mut self) -> Result<String, OxcDiagnostic> {
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            match c {
-                '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' => {}
-                '-' => {
-                    if i == 0 {
-                        len = 0;
-                        break;
-                    }
+    fn lower_element(&mut self, element: ir::Element) -> Result<TNode, OxcDiagnostic> {
+        let (tag, tag_expr) = match element.tag {
+            ir::Tag::Static(name) => (name.value, None),
+            ir::Tag::Expr(e) => (String::new(), Some(ExprIndex(e.index))),
+        };
+        let mut bindings = Vec::new();
+        let mut properties = Vec::with_capacity(element.properties.len());
+        for p in element.properties {
+            properties.push(match p {
+                ir::Property::Attribute(p) => {
+                    let key = p.name.value;
+                    let value = match p.value {
+                        None => TPropertyAttributeValue::Bool,
+                        Some(ir::Value::String(s)) => TPropertyAttributeValue::String(s.value),
+                        Some(ir::Value::Expr(e)) => {
+                            // Hoist symbols from the root scope
+                            let hoist = key == "class"
+                                && is_hoistable_expr(&self.expressions[e.index], self.scoping);
+                            TPropertyAttributeValue::Expr(THoistableExpr {
+                                index: ExprIndex(e.index),
+                                hoist,
+                            })
+                        }
+                    };
+                    TProperty::Attribute(TPropertyAttribute { key, value })
                 }
-                _ => {
-                    len = i;
-                    break;
+                ir::Property::Value(p) => {
+                    self.check_inner_html(&p.name.value, p.value.index);
+                    TProperty::Value(TPropertyValue {
+                        key: p.name.value,
+                        value: ExprIndex(p.value.index),
+                    })
                 }
-            }
-        }
-        if len > 0 {
-            let id = self.text[..len].to_string();
-            self.advance(len);
-            Ok(id)
-        } else {
-            let parts = self.text.split_at(self.text.len().min(10));
-            Err(OxcDiagnostic::error(format!("Invalid attribute name: {}", parts.0))
-                .with_label(self.current_element().span))
-        }
-    }
-
-    fn parse_js_property(&mut self) -> Result<String, OxcDiagnostic> {
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            match c {
-                '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '$' => {}
-                _ => {
-                    len = i;
-                    break;
+                ir::Property::DOMValue(p) => {
+                    self.check_inner_html(&p.name.value, p.value.index);
+                    TProperty::DOMValue(TPropertyDOMValue {
+                        key: p.name.value,
+                        value: ExprIndex(p.value.index),
+                    })
                 }
-            }
-        }
-        if len > 0 {
-            let id = self.text[..len].to_string();
-            self.advance(len);
-            Ok(id)
-        } else {
-            let parts = self.text.split_at(self.text.len().min(10));
-            Err(OxcDiagnostic::error(format!("Invalid property name: {}", parts.0))
-                .with_label(self.current_element().span))
-        }
-    }
-
-    fn parse_style_name(&mut self) -> Result<String, OxcDiagnostic> {
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            match c {
-                '0'..='9' | 'a'..='z' | 'A'..='Z' | '-' | '_' => {}
-                _ => {
-                    len = i;
-                    break;
+                ir::Property::Style(p) => TProperty::Style(TPropertyStyle {
+                    key: p.name.value,
+                    value: match p.value {
+                        ir::Value::String(s) => TPropertyStyleValue::String(s.value),
+                        ir::Value::Expr(e) => TPropertyStyleValue::Expr(ExprIndex(e.index)),
+                    },
+                }),
+                ir::Property::Event(p) => TProperty::Event(TPropertyEvent {
+                    key: p.name.value,
+                    modifiers: p.modifiers.iter().fold(0, |m, n| m | event_modifier(&n.value)),
+                    value: ExprIndex(p.value.index),
+                    hoist: true,
+                }),
+                ir::Property::Binding(p) => {
+                    bindings.push((p.name.value, ExprIndex(p.value.index)));
+                    continue;
                 }
-            }
-        }
-        if len > 0 {
-            let id = self.text[..len].to_string();
-            self.advance(len);
-            Ok(id)
-        } else {
-            let parts = self.text.split_at(self.text.len().min(10));
-            Err(OxcDiagnostic::error(format!("Invalid style name: {}", parts.0))
-                .with_label(self.current_element().span))
+                ir::Property::Ref(p) => TProperty::Ref(ExprIndex(p.value.index)),
+                ir::Property::Directive(e) => TProperty::Directive(ExprIndex(e.index)),
+            });
         }
-    }
-
-    fn parse_children_list(&mut self) -> Result<Vec<TNode>, OxcDiagnostic> {
-        let mut children = Vec::new();
-        let mut whitespace_state = self.consume_whitespace();
-        while !self.is_end() {
-            if let Some(c) = self.peek_char() {
-                if c == '<' {
-                    if whitespace_state.should_insert_whitespace() {
-                        children.push(TNode::space_text());
-                    }
-                    match self.peek_nth_char(1) {
-                        Some('/') => {
-                            break;
-                        }
-                        Some('!') => {
-                            self.parse_comment();
-                        }
-                        _ => {
-                            children.push(TNode::new(TNodeKind::Element(self.parse_element()?)));
-                        }
-                    }
-                } else {
-                    children.push(TNode::new(TNodeKind::Text(self.parse_text(whitespace_state)?)));
+        for (key, expr) in bindings {
+            let event = match binding_event(&tag, &properties, &key) {
+                Ok(event) => event,
+                Err(err) => {
+                    return Err(OxcDiagnostic::error(err).with_label(element.span));
                 }
-            } else {
-                let index = self.consume_expr()?;
-                if whitespace_state.should_insert_whitespace() {
-                    children.push(TNode::space_text());
-                }
-                children.push(TNode::new(TNodeKind::Expr(TExpr { index: ExprIndex(index) })));
-            }
-
-            whitespace_state = self.consume_whitespace();
-        }
-        Ok(children)
-    }
-
-    fn parse_comment(&mut self) {
-        let mut len = self.text.len();
-        for (i, c) in self.text.char_indices() {
-            if c == '>' {
-                len = i + 1;
-                break;
-            }
+            };
+            let value = self.reserve_expr();
+            let handler = self.reserve_expr();
+            properties.push(TProperty::DOMValue(TPropertyDOMValue { key: key.clone(), value }));
+            properties.push(TProperty::Event(TPropertyEvent {
+                key: event.to_string(),
+                modifiers: 0,
+                value: handler,
+                hoist: false,
+            }));
+            self.bindings.push(TBinding { key, expr, value, handler });
         }
-        if len > 0 {
-            self.advance(len);
+        // HTML attributes aren't normalized in SVG and MathML namespaces.
+        // <svg><rect hidden=${v} /></svg>
+        let foreign = self.foreign || matches!(tag.as_str(), "svg" | "math");
+        if let TemplateKind::Html = self.kind
+            && !foreign
+        {
+            self.normalize_html_attributes(&tag, &mut properties);
         }
-    }
-
-    fn parse_element(&mut self) -> Result<TElement, OxcDiagnostic> {
-        self.advance(1);
-        let tag = self.parse_tag_name()?;
-        self.consume_whitespace();
-        let properties = self.parse_attributes()?;
 
-        let mut children = Vec::new();
-        let mut void = false;
-        if self.try_consume_char('/').is_some() {
-            self.consume_char('>')?;
+        let parent_foreign = std::mem::replace(&mut self.foreign, foreign);
+        let children = self.lower_children(element.children)?;
+        self.foreign = parent_foreign;
+        // Self-closing void elements don't have closing tags: `<input />`.
+        let void = element.void
+            || (matches!(self.kind, TemplateKind::Html) && !foreign && is_html_void_element(&tag));
+        let element = TElement { tag, properties, children, void };
+        if let Some(tag) = tag_expr {
+            Ok(self.lift_dynamic_element(tag, element))
         } else {
-            self.consume_char('>')?;
-
-            if is_html_void_element(&tag) {
-                void = true;
-            } else {
-                children = self.parse_children_list()?;
-                self.consume_char('<')?;
-                self.consume_char('/')?;
-                self.advance(tag.len());
-                self.consume_whitespace();
-                self.consume_char('>')?;
-            }
+            Ok(TNode::new(TNodeKind::Element(element)))
         }
-        Ok(TElement { tag, properties, children, void })
     }
 
-    fn parse_text(&mut self, whitespace_state: WhitespaceState) -> Result<TText, OxcDiagnostic> {
-        let mut text = String::new();
-        let mut len = self.text.len();
-        let mut whitespace_state = whitespace_state;
-        for (i, c) in self.text.char_indices() {
-            match c {
-                '<' => {
-                    len = i;
-                    break;
-                }
-                ' ' | '\t' => {
-                    whitespace_state.0 |= WhitespaceState::WHITESPACE;
-                    continue;
-                }
-                '\n' | '\r' => {
-                    whitespace_state.0 |= WhitespaceState::CONTAINS_NEWLINE;
-                    continue;
-                }
-                '\x0b' => {
-                    whitespace_state.0 |= WhitespaceState::CONTAINS_VERTICAL_TAB;
-                    continue;
-                }
-                _ => {}
+    // disabled=${v} => .disabled=${v}
+    // checked=${v}  => *checked=${v}
+    fn normalize_html_attributes(&mut self, tag: &str, properties: &mut [TProperty]) {
+        for p in properties.iter_mut() {
+            let TProperty::Attribute(TPropertyAttribute {
+                key,
+                value: TPropertyAttributeValue::Expr(v),
+            }) = p
+            else {
+                continue;
+            };
+            let value = v.index;
+            if let Some(property) = html_boolean_attribute_property(tag, key) {
+                *p = TProperty::Value(TPropertyValue { key: property.to_string(), value });
+            } else if is_html_form_state_attribute(tag, key) {
+                self.warnings.push(
+                    WarningCode::FormStateAttribute.warn(format!(
+                        "'{key}' attribute sets only the initial state of the '{tag}' element"
+                    ))
+                    .with_help(format!(
+                        "'{key}' is updated as a '*{key}' DOM property, use '*{key}=${{..}}' to make it explicit"
+                    ))
+                    .with_label(self.expressions[value.inner()].span()),
+                );
+                let key = std::mem::take(key);
+                *p = TProperty::DOMValue(TPropertyDOMValue { key, value });
             }
-            if whitespace_state.0 & WhitespaceState::WHITESPACE != 0
-                && (whitespace_state.0
-                    & (WhitespaceState::TEXT_CONTENT | WhitespaceState::CONTAINS_VERTICAL_TAB)
-                    != 0
-                    || whitespace_state.0 & WhitespaceState::CONTAINS_NEWLINE == 0)
-            {
-                text.push(' ');
-            }
-            whitespace_state.0 = WhitespaceState::TEXT_CONTENT;
-            text.push(c);
-        }
-        if whitespace_state.should_insert_whitespace() {
-            text.push(' ');
-        }
-        self.advance(len);
-        if text.len() <= (1 << 16) {
-            Ok(TText { value: text })
-        } else {
-            // Text nodes are splitted into two nodes when they exceed their length limit (64k).
-            // https://github.com/chromium/chromium/blob/91159249db3086f17b28b7a060f55ec0345c24c7/third_party/blink/renderer/core/dom/text.h#L42
-            Err(OxcDiagnostic::error("Text is too long (>64Kb)")
-                .with_label(self.current_element().span))
         }
     }
 
-    fn parse_attributes(&mut self) -> Result<Vec<TProperty>, OxcDiagnostic> {
-        let mut properties = Vec::new();
-
-        while !self.is_end() {
-            if let Some(c) = self.peek_char() {
-                match c {
-                    '/' | '>' => {
-                        return Ok(properties);
-                    }
-                    '.' => {
-                        self.advance(1);
-                        let key = self.parse_js_property()?;
-                        self.consume_char('=')?;
-                        let expr_index = self.consume_expr()?;
-                        properties.push(TProperty::Value(TPropertyValue {
-                            key,
-                            value: ExprIndex(expr_index),
-                        }));
-                    }
-                    '*' => {
-                        self.advance(1);
-                        let key = self.parse_js_property()?;
-                        self.consume_char('=')?;
-                        let expr_index = self.consume_expr()?;
-                        properties.push(TProperty::DOMValue(TPropertyDOMValue {
-                            key,
-                            value: ExprIndex(expr_index),
-                        }));
-                    }
-                    '@' => {
-                        self.advance(1);
-                        let key = self.parse_js_property()?;
-                        self.consume_char('=')?;
-                        let expr_index = self.consume_expr()?;
-                        properties.push(TProperty::Event(TPropertyEvent {
-                            key,
-                            value: ExprIndex(expr_index),
-                        }));
-                    }
-                    '~' => {
-                        self.advance(1);
-                        let key = self.parse_style_name()?;
-                        self.consume_char('=')?;
-                        let value = if self.peek_char().is_some() {
-                            TPropertyStyleValue::String(self.parse_attribute_string()?)
-                        } else {
-                            TPropertyStyleValue::Expr(ExprIndex(self.consume_expr()?))
-                        };
-                        properties.push(TProperty::Style(TPropertyStyle { key, value }));
-                    }
-                    _ => {
-                        let key = self.parse_attribute_name()?;
-                        let mut hoist = false;
-                        let value;
-                        if self.try_consume_char('=').is_some() {
-                            if let Some('"') = self.peek_char() {
-                                value =
-                                    TPropertyAttributeValue::String(self.parse_attribute_string()?);
-                            } else {
-                                let expr_index = self.consume_expr()?;
-                                if key == "class" {
-                                    let expr = &self.expressions[expr_index];
-                                    // Hoist symbols from the root scope
-                                    if is_hoistable_expr(expr, self.scoping) {
-                                        hoist = true;
-                                    }
-                                }
-                                value = TPropertyAttributeValue::Expr(THoistableExpr {
-                                    index: ExprIndex(expr_index),
-                                    hoist,
-                                });
-                            }
-                        } else {
-                            value = TPropertyAttributeValue::Bool;
-                        }
-                        properties.push(TProperty::Attribute(TPropertyAttribute { key, value }));
-                    }
-                }
-            } else {
-                properties.push(TProperty::Directive(ExprIndex(self.consume_expr()?)))
-            }
-            self.consume_whitespace();
-        }
-
-        let parts = self.text.split_at(self.text.len().min(10));
-        Err(OxcDiagnostic::error(format!("Expected a '>' char: {}", parts.0))
-            .with_label(self.current_element().span))
+    fn lift_dynamic_element(&mut self, tag: ExprIndex, mut element: TElement) -> TNode {
+        // Static template is created at runtime, so it can't contain hoisted
+        // expressions.
+        disable_hoisting(&mut element);
+        let index = self.reserve_expr();
+        let mut node = TNode::new(TNodeKind::Element(element));
+        update_flags(&mut node);
+        assign_state_slots(&mut node);
+        self.dynamic_elements.push(TDynamicElement { index, tag, node });
+        TNode::new(TNodeKind::Expr(TExpr { index }))
     }
 
-    fn parse_attribute_string(&mut self) -> Result<String, OxcDiagnostic> {
-        let delim;
-        let mut chars = self.text.char_indices();
-        if let Some((_, c)) = chars.next() {
-            match c {
-                '\'' | '"' => {
-                    delim = c;
-                }
-                _ => {
-                    return Err(OxcDiagnostic::error(
-                        "Invalid string value, it should start with '\"' char.",
+    // .innerHTML=${html}
+    fn check_inner_html(&mut self, key: &str, expr_index: usize) {
+        let expr = &self.expressions[expr_index];
+        let is_literal = match expr {
+            Expression::StringLiteral(_) => true,
+            Expression::TemplateLiteral(t) => t.expressions.is_empty(),
+            _ => false,
+        };
+        if key == "innerHTML" && !is_literal {
+            self.warnings.push(
+                WarningCode::InnerHtml
+                    .warn("'innerHTML' is assigned a non-literal value")
+                    .with_help(
+                        "Values assigned to 'innerHTML' are parsed as HTML, make sure that they \
+                         are sanitized",
                     )
-                    .with_label(self.current_element().span));
-                }
-            }
-            for (i, c) in chars {
-                if c == delim {
-                    let v = self.text[1..i].to_string();
-                    self.advance(i + 1);
-                    return Ok(v);
-                }
-            }
-            Err(OxcDiagnostic::error("Invalid string value, it should end with '\"' char.")
-                .with_label(self.current_element().span))
-        } else {
-            Err(OxcDiagnostic::error("Invalid string value")
-                .with_label(self.current_element().span))
+                    .with_label(expr.span()),
+            );
         }
     }
 }
 
-#[derive(Clone, Copy)]
-struct WhitespaceState(u8);
-
-impl WhitespaceState {
-    const WHITESPACE: u8 = 1;
-    const CONTAINS_NEWLINE: u8 = 1 << 1;
-    const CONTAINS_VERTICAL_TAB: u8 = 1 << 2;
-    const TEXT_CONTENT: u8 = 1 << 3;
-
-    fn should_insert_whitespace(self) -> bool {
-        self.0 & WhitespaceState::WHITESPACE != 0
-            && (self.0 & WhitespaceState::CONTAINS_NEWLINE == 0
-                || self.0 & WhitespaceState::CONTAINS_VERTICAL_TAB != 0)
+fn event_modifier(name: &str) -> u32 {
+    match name {
+        "passive" => event_modifier::PASSIVE,
+        "capture" => event_modifier::CAPTURE,
+        "once" => event_modifier::ONCE,
+        "prevent" => event_modifier::PREVENT,
+        "stop" => event_modifier::STOP,
+        "self" => event_modifier::SELF,
+        _ => 0,
     }
 }
 
@@ -625,7 +426,8 @@ fn _update_flags(node: &mut TNode, flags: u8) -> u8 {
                 TProperty::Value(_)
                 | TProperty::DOMValue(_)
                 | TProperty::Event(_)
-                | TProperty::Directive(_) => {
+                | TProperty::Directive(_)
+                | TProperty::Ref(_) => {
                     props_exprs += 1;
                     break;
                 }
@@ -704,6 +506,60 @@ fn _assign_state_slots(node: &mut TNode, mut state_index: u16) -> u16 {
     state_index
 }
 
+// Returns an event that should be used to update bound value.
+fn binding_event(tag: &str, properties: &[TProperty], key: &str) -> Result<&'static str, String> {
+    match tag {
+        "input" => {
+            let ty = properties.iter().find_map(|p| match p {
+                TProperty::Attribute(p) if p.key == "type" => Some(&p.value),
+                _ => None,
+            });
+            let ty = match ty {
+                None => "text",
+                Some(TPropertyAttributeValue::String(ty)) => ty.as_str(),
+                Some(_) => {
+                    return Err(
+                        "Input elements with a dynamic 'type' attribute can't be bound".to_string()
+                    );
+                }
+            };
+            match (ty, key) {
+                ("checkbox" | "radio", "checked") => Ok("change"),
+                ("checkbox" | "radio", _) => {
+                    Err(format!("'{ty}' input elements can be bound only with 'bind:checked'"))
+                }
+                ("file", _) => Err("'file' input elements can't be bound".to_string()),
+                (_, "value" | "valueAsNumber" | "valueAsDate") => Ok("input"),
+                _ => Err(format!("'{ty}' input elements can't be bound with 'bind:{key}'")),
+            }
+        }
+        "textarea" if key == "value" => Ok("input"),
+        "select" if key == "value" => Ok("change"),
+        "textarea" | "select" => {
+            Err(format!("'{tag}' elements can be bound only with 'bind:value'"))
+        }
+        "" => Err("Elements with dynamic tag names can't be bound".to_string()),
+        _ => Err(format!("'{tag}' elements can't be bound")),
+    }
+}
+
+fn disable_hoisting(element: &mut TElement) {
+    for p in &mut element.properties {
+        if let TProperty::Attribute(TPropertyAttribute {
+            value: TPropertyAttributeValue::Expr(v),
+            ..
+        }) = p
+        {
+            v.hoist = false;
+        }
+    }
+    for c in &mut element.children {
+        if let TNodeKind::Element(e) = &mut c.kind {
+            disable_hoisting(e);
+        }
+    }
+}
+
 fn is_hoistable_expr<'a>(expr: &Expression<'a>, scoping: &Scoping) -> bool {
     match expr {
         Expression::Identifier(id) => {
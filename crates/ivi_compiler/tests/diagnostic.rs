@@ -0,0 +1,80 @@
+use ivi_compiler::{CompilerError, CompilerOptions, DiagnosticFormat, ModuleType, compile_module};
+use rustc_hash::FxHashSet;
+
+const ERROR_SOURCE: &str = "import { html } from \"ivi\";\n// €\nconst a = html`€<div></span>`;\n";
+const WARNING_SOURCE: &str = "import { html } from \"ivi\";\nconst a = [\"€\", html];\n";
+
+fn compile(source_text: &str) -> Result<ivi_compiler::CompilerOutput, CompilerError> {
+    compile_module(
+        source_text,
+        "src/app.js",
+        ModuleType::Js,
+        None,
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+}
+
+#[test]
+fn error_label() {
+    let err = compile(ERROR_SOURCE).err().unwrap();
+    let label = &err.diagnostic().unwrap().labels[0];
+    assert_eq!((label.start, label.line, label.column), (63, 3, 27));
+}
+
+#[test]
+fn error_plain() {
+    let err = compile(ERROR_SOURCE).err().unwrap();
+    assert_eq!(
+        err.render("src/app.js", ERROR_SOURCE, DiagnosticFormat::Plain),
+        "src/app.js:3:27: error: Expected a '>' char: n>"
+    );
+}
+
+#[test]
+fn error_graphical() {
+    let err = compile(ERROR_SOURCE).err().unwrap();
+    let graphical = err.render("src/app.js", ERROR_SOURCE, DiagnosticFormat::Graphical);
+    assert!(graphical.starts_with("Invalid template: "), "{graphical}");
+    assert!(graphical.contains("Expected a '>' char: n>"), "{graphical}");
+    // Graphical reports count columns in bytes.
+    assert!(graphical.contains("[src/app.js:3:29]"), "{graphical}");
+    assert!(graphical.contains(" 3 | const a = html`€<div></span>`;"), "{graphical}");
+}
+
+#[test]
+fn error_json() {
+    let err = compile(ERROR_SOURCE).err().unwrap();
+    let json = err.render("src/app.js", ERROR_SOURCE, DiagnosticFormat::Json);
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "file": "src/app.js",
+            "severity": "error",
+            "code": null,
+            "message": "Expected a '>' char: n>",
+            "labels": [{ "start": 63, "end": 64, "line": 3, "column": 27, "message": null }],
+            "help": null,
+            "related": [],
+        })
+    );
+}
+
+#[test]
+fn warning_formats() {
+    let output = compile(WARNING_SOURCE).unwrap();
+    let diagnostic = &output.diagnostics[0];
+    let plain = diagnostic.render("src/app.js", WARNING_SOURCE, DiagnosticFormat::Plain);
+    assert!(plain.starts_with("src/app.js:2:17: warning[unsupported-template]: "), "{plain}");
+    let graphical = diagnostic.render("src/app.js", WARNING_SOURCE, DiagnosticFormat::Graphical);
+    assert!(graphical.contains(" 2 | const a = [\"€\", html];"), "{graphical}");
+    assert!(graphical.contains(":                 ^^^^"), "{graphical}");
+    let json = diagnostic.render("src/app.js", WARNING_SOURCE, DiagnosticFormat::Json);
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json["severity"], "warning");
+    assert_eq!(json["code"], "unsupported-template");
+    assert_eq!(json["labels"][0]["start"], 46);
+    assert_eq!(json["labels"][0]["line"], 2);
+    assert_eq!(json["labels"][0]["column"], 17);
+}
@@ -0,0 +1,263 @@
+use ivi_compiler::{
+    CompilerOptions, DiagnosticFormat, ModuleType, WarningCode, compile_module,
+    ir::{self, Span},
+};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+
+// Parses a template from the first tagged template expression statement.
+fn parse_template(source_text: &str) -> Result<ir::Template, ir::ParseError> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+    assert!(ret.diagnostics.is_empty());
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+        panic!("expected an expression statement");
+    };
+    let Expression::TaggedTemplateExpression(expr) = &stmt.expression else {
+        panic!("expected a tagged template");
+    };
+    ir::parse_template_literal(&expr.quasi)
+}
+
+fn text(source_text: &str, span: Span) -> &str {
+    &source_text[span.start as usize..span.end as usize]
+}
+
+fn element(node: &ir::Node) -> &ir::Element {
+    match node {
+        ir::Node::Element(e) => e,
+        _ => panic!("expected an element"),
+    }
+}
+
+fn tag_name(element: &ir::Element) -> &ir::Name {
+    match &element.tag {
+        ir::Tag::Static(name) => name,
+        ir::Tag::Expr(_) => panic!("expected a static tag"),
+    }
+}
+
+#[test]
+fn spans() {
+    let s = "html`<div class=\"a\" id=${v}>text<br /></div>`";
+    let t = parse_template(s).unwrap();
+    assert_eq!(text(s, t.span), "<div class=\"a\" id=${v}>text<br /></div>");
+    let div = element(&t.nodes[0]);
+    assert_eq!(text(s, div.span), "<div class=\"a\" id=${v}>text<br /></div>");
+    assert_eq!(text(s, tag_name(div).span), "div");
+    let [ir::Property::Attribute(class), ir::Property::Attribute(id)] = &div.properties[..] else {
+        panic!("expected attributes");
+    };
+    assert_eq!(text(s, class.span), "class=\"a\"");
+    assert_eq!(text(s, class.name.span), "class");
+    let Some(ir::Value::String(value)) = &class.value else {
+        panic!("expected a string value");
+    };
+    assert_eq!(text(s, value.span), "\"a\"");
+    assert_eq!(text(s, id.span), "id=${v}");
+    let ir::Node::Text(t) = &div.children[0] else {
+        panic!("expected a text node");
+    };
+    assert_eq!(text(s, t.span), "text");
+    let br = element(&div.children[1]);
+    assert_eq!(text(s, br.span), "<br />");
+    assert!(br.self_closing);
+}
+
+#[test]
+fn escapes() {
+    let s = r#"html`\n\u00e9\x41<b title="\u{1F600}\uD83D\uDE00">\`</b>`"#;
+    let t = parse_template(s).unwrap();
+    let ir::Node::Text(text_node) = &t.nodes[0] else {
+        panic!("expected a text node");
+    };
+    assert_eq!(text_node.value, "éA");
+    let b = element(&t.nodes[1]);
+    assert_eq!(text(s, b.span), r#"<b title="\u{1F600}\uD83D\uDE00">\`</b>"#);
+    assert_eq!(text(s, tag_name(b).span), "b");
+    let ir::Property::Attribute(title) = &b.properties[0] else {
+        panic!("expected an attribute");
+    };
+    assert_eq!(text(s, title.span), r#"title="\u{1F600}\uD83D\uDE00""#);
+    let Some(ir::Value::String(value)) = &title.value else {
+        panic!("expected a string value");
+    };
+    assert_eq!(value.value, "😀😀");
+    let ir::Node::Text(text_node) = &b.children[0] else {
+        panic!("expected a text node");
+    };
+    assert_eq!(text(s, text_node.span), r"\`");
+}
+
+#[test]
+fn line_continuations() {
+    let s = "html`<a>\\\n</a><b></b>`";
+    let t = parse_template(s).unwrap();
+    assert_eq!(text(s, element(&t.nodes[0]).span), "<a>\\\n</a>");
+    assert_eq!(text(s, element(&t.nodes[1]).span), "<b></b>");
+}
+
+#[test]
+fn non_ascii() {
+    let s = "html`\\t€<span bind:foo=${x}></span>`";
+    let t = parse_template(s).unwrap();
+    let ir::Node::Text(text_node) = &t.nodes[0] else {
+        panic!("expected a text node");
+    };
+    assert_eq!(text(s, text_node.span), "\\t€");
+    let span = element(&t.nodes[1]);
+    assert_eq!(text(s, span.span), "<span bind:foo=${x}></span>");
+    assert_eq!(text(s, tag_name(span).span), "span");
+    let ir::Property::Binding(binding) = &span.properties[0] else {
+        panic!("expected a binding");
+    };
+    assert_eq!(text(s, binding.span), "bind:foo=${x}");
+    assert_eq!(text(s, binding.name.span), "foo");
+    assert_eq!(text(s, binding.value.span), "x");
+}
+
+#[test]
+fn quasis() {
+    // html`<p>${a}</p>`
+    let quasis =
+        [ir::Quasi::new("<p>", Span::new(5, 8)), ir::Quasi::new("</p>", Span::new(12, 16))];
+    let t = ir::parse_template_str(&quasis, &[Span::new(10, 11)]).unwrap();
+    assert_eq!(t.span, Span::new(5, 16));
+    assert_eq!(element(&t.nodes[0]).span, Span::new(5, 16));
+
+    // html`\x41<p></p>`
+    let quasis = [ir::Quasi::with_raw("A<p></p>", "\\x41<p></p>", Span::new(5, 16))];
+    let t = ir::parse_template_str(&quasis, &[]).unwrap();
+    assert_eq!(element(&t.nodes[1]).span, Span::new(9, 16));
+}
+
+#[test]
+fn diagnostics() {
+    let err =
+        ir::parse_template_str(&[ir::Quasi::new("<p>", Span::new(5, 8))], &[Span::new(9, 10)])
+            .unwrap_err();
+    assert_eq!(err.message, "Template with 1 expressions should have 2 quasis");
+    assert!(err.span.is_unspanned());
+
+    // Errors are reported at the error position.
+    let s = "html`<div>${a}</span>`";
+    let err = parse_template(s).unwrap_err();
+    assert_eq!(err.message, "Expected a '>' char: n>");
+    assert_eq!(text(s, err.span), "n");
+
+    let s = "html`<div @click.once.foo=${a}></div>`";
+    let err = parse_template(s).unwrap_err();
+    assert_eq!(text(s, err.span), "foo");
+
+    let s = "html`<div a=\"1></div>`";
+    let err = parse_template(s).unwrap_err();
+    assert_eq!(text(s, err.span), "\"1></div>");
+
+    let s = "html`<div>text\n  ${a}</div>`";
+    let t = parse_template(s).unwrap();
+    assert_eq!(t.warnings.len(), 1);
+    assert_eq!(t.warnings[0].code, WarningCode::SuspiciousWhitespace);
+    assert_eq!(text(s, t.warnings[0].span), "\n  ");
+    assert!(t.warnings[0].help.is_some());
+
+    let s = "html`<div>${a}\n  text</div>`";
+    let t = parse_template(s).unwrap();
+    assert_eq!(text(s, t.warnings[0].span), "\n  ");
+}
+
+#[derive(Default)]
+struct Visitor(Vec<String>);
+
+impl ir::Visit for Visitor {
+    fn visit_element(&mut self, element: &ir::Element) {
+        match &element.tag {
+            ir::Tag::Static(name) => self.0.push(format!("<{}>", name.value)),
+            ir::Tag::Expr(_) => self.0.push("<>".to_string()),
+        }
+        ir::walk_element(self, element);
+        self.0.push("</>".to_string());
+    }
+
+    fn visit_property(&mut self, property: &ir::Property) {
+        let name = match property {
+            ir::Property::Attribute(p) => p.name.value.clone(),
+            ir::Property::Value(p) => format!(".{}", p.name.value),
+            ir::Property::DOMValue(p) => format!("*{}", p.name.value),
+            ir::Property::Style(p) => format!("~{}", p.name.value),
+            ir::Property::Event(p) => format!("@{}", p.name.value),
+            ir::Property::Binding(p) => format!("bind:{}", p.name.value),
+            ir::Property::Ref(_) => "ref".to_string(),
+            ir::Property::Directive(_) => "directive".to_string(),
+        };
+        self.0.push(name);
+        ir::walk_property(self, property);
+    }
+
+    fn visit_text(&mut self, text: &ir::Text) {
+        self.0.push(format!("'{}'", text.value));
+    }
+
+    fn visit_expr(&mut self, expr: &ir::Expr) {
+        self.0.push(format!("${}", expr.index));
+    }
+
+    fn visit_comment(&mut self, comment: &ir::Comment) {
+        self.0.push(comment.value.clone());
+    }
+}
+
+#[test]
+fn visitor_order() {
+    let s = "html`<div a=${a} .b=${b} ~c=\"1\" @click=${c} ${d}>x<!--y-->${e}<${f} ref=${g}></></div><p></p>`";
+    let t = parse_template(s).unwrap();
+    let mut visitor = Visitor::default();
+    ir::Visit::visit_template(&mut visitor, &t);
+    assert_eq!(
+        visitor.0,
+        [
+            "<div>",
+            "a",
+            "$0",
+            ".b",
+            "$1",
+            "~c",
+            "@click",
+            "$2",
+            "directive",
+            "$3",
+            "'x'",
+            "<!--y-->",
+            "$4",
+            "<>",
+            "$5",
+            "ref",
+            "$6",
+            "</>",
+            "</>",
+            "<p>",
+            "</>",
+        ]
+    );
+}
+
+#[test]
+fn non_ascii_compile() {
+    let s = "import { html } from \"ivi\";\nconst a = html`\\t€<span bind:foo=${x}></span>`;\n";
+    let err = compile_module(
+        s,
+        "src/app.js",
+        ModuleType::Js,
+        None,
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.render("src/app.js", s, DiagnosticFormat::Plain),
+        "src/app.js:2:19: error: 'span' elements can't be bound"
+    );
+}
@@ -0,0 +1,52 @@
+use ivi_compiler::{CompilerError, ModuleType};
+
+#[test]
+fn from_extension() {
+    assert_eq!(ModuleType::from_extension("js"), Some(ModuleType::Js));
+    assert_eq!(ModuleType::from_extension("mjs"), Some(ModuleType::Js));
+    assert_eq!(ModuleType::from_extension("cjs"), Some(ModuleType::Cjs));
+    assert_eq!(ModuleType::from_extension("jsx"), Some(ModuleType::Jsx));
+    assert_eq!(ModuleType::from_extension("ts"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_extension("mts"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_extension("cts"), Some(ModuleType::Cts));
+    assert_eq!(ModuleType::from_extension("tsx"), Some(ModuleType::Tsx));
+    assert_eq!(ModuleType::from_extension("vue"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_extension("svelte"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_extension("astro"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_extension("css"), None);
+    assert_eq!(ModuleType::from_extension(""), None);
+}
+
+#[test]
+fn from_path() {
+    assert_eq!(ModuleType::from_path("src/app.js"), Some(ModuleType::Js));
+    assert_eq!(ModuleType::from_path("src/app.d.cts"), Some(ModuleType::Cts));
+    assert_eq!(ModuleType::from_path("/src/App.tsx"), Some(ModuleType::Tsx));
+    assert_eq!(ModuleType::from_path("src/App.vue"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_path("src/App.svelte"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_path("src/Page.astro"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_path("src/styles.css"), None);
+    assert_eq!(ModuleType::from_path("src/app"), None);
+    assert_eq!(ModuleType::from_path(""), None);
+}
+
+#[test]
+fn from_path_query() {
+    assert_eq!(
+        ModuleType::from_path("src/App.vue?vue&type=script&setup=true&lang.tsx"),
+        Some(ModuleType::Tsx)
+    );
+    assert_eq!(ModuleType::from_path("src/App.vue?vue&type=script&lang.js"), Some(ModuleType::Js));
+    assert_eq!(ModuleType::from_path("src/App.vue?vue&type=script"), Some(ModuleType::Ts));
+    assert_eq!(ModuleType::from_path("src/App.vue?vue&type=style&lang.css"), None);
+    assert_eq!(ModuleType::from_path("src/app.ts?import"), Some(ModuleType::Ts));
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("tsx".parse::<ModuleType>().ok(), Some(ModuleType::Tsx));
+    assert_eq!("vue".parse::<ModuleType>().ok(), Some(ModuleType::Ts));
+    let err = "css".parse::<ModuleType>().unwrap_err();
+    assert!(matches!(&err, CompilerError::ModuleType(s) if s == "css"));
+    assert_eq!(err.to_string(), "Invalid module type: css");
+}
@@ -0,0 +1,185 @@
+use ivi_compiler::{CompilerOptions, DiagnosticFormat, ModuleType, compile_module};
+use oxc_sourcemap::{SourceMap, SourceMapBuilder};
+use rustc_hash::FxHashSet;
+
+fn compile(source_text: &str, filename: &str, options: &CompilerOptions) -> serde_json::Value {
+    let output = compile_module(
+        source_text,
+        filename,
+        ModuleType::Js,
+        None,
+        options,
+        &mut FxHashSet::default(),
+    )
+    .unwrap();
+    serde_json::from_str(&output.map).unwrap()
+}
+
+#[test]
+fn sources() {
+    let source_text = "import { html } from \"ivi\";\nconst a = html`<div></div>`;\n";
+    let map = compile(source_text, "src/app.js", &CompilerOptions::default());
+    assert_eq!(map["sources"], serde_json::json!(["src/app.js"]));
+    assert_eq!(map["sourcesContent"], serde_json::json!([source_text]));
+}
+
+#[test]
+fn sources_without_content() {
+    let source_text = "import { html } from \"ivi\";\nconst a = html`<div></div>`;\n";
+    let options = CompilerOptions::builder().sources_content(false).build();
+    let map = compile(source_text, "src/app.js", &options);
+    assert_eq!(map["sources"], serde_json::json!(["src/app.js"]));
+    assert_eq!(map.get("sourcesContent"), None);
+}
+
+#[test]
+fn diagnostic_filename() {
+    let source_text = "import { html } from \"ivi\";\nconst a = html`<div></span>`;\n";
+    let err = compile_module(
+        source_text,
+        "src/app.js",
+        ModuleType::Js,
+        None,
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+    .err()
+    .unwrap();
+    let plain = err.render("src/app.js", source_text, DiagnosticFormat::Plain);
+    assert!(plain.starts_with("src/app.js:2:26: error: "), "{plain}");
+    let graphical = err.render("src/app.js", source_text, DiagnosticFormat::Graphical);
+    assert!(graphical.contains("[src/app.js:2:26]"), "{graphical}");
+}
+
+#[test]
+fn warning_filename() {
+    let source_text = "import { html } from \"ivi\";\nconst a = [html];\n";
+    let output = compile_module(
+        source_text,
+        "src/app.js",
+        ModuleType::Js,
+        None,
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+    .unwrap();
+    let plain = output.diagnostics[0].render("src/app.js", source_text, DiagnosticFormat::Plain);
+    assert!(plain.starts_with("src/app.js:2:12: warning[unsupported-template]: "), "{plain}");
+}
+
+// `app.ts` with stripped type annotations.
+const TS_SOURCE: &str = "import { html } from \"ivi\";
+const n: number = 1;
+export const a = html`<div>${n}</div>`;
+";
+const JS_SOURCE: &str = "import { html } from \"ivi\";
+const n = 1;
+export const a = html`<div>${n}</div>`;
+";
+
+// `[line, column]` in `JS_SOURCE` -> `[line, column]` in `TS_SOURCE`
+fn ts_input_map(mappings: &[[u32; 4]]) -> String {
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.add_source_and_content("app.ts", TS_SOURCE);
+    for &[dst_line, dst_col, src_line, src_col] in mappings {
+        builder.add_token(dst_line, dst_col, src_line, src_col, Some(source_id), None);
+    }
+    builder.into_sourcemap().to_json_string()
+}
+
+// Finds generated position of the `needle` and looks up its original position.
+fn original_position(code: &str, map: &SourceMap, needle: &str) -> Option<(String, u32, u32)> {
+    let offset = code.find(needle).unwrap();
+    let line = code[..offset].matches('\n').count() as u32;
+    let column = (offset - code[..offset].rfind('\n').map_or(0, |i| i + 1)) as u32;
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_token(&lookup_table, line, column).unwrap();
+    let source = map.get_source(token.get_source_id()?)?;
+    Some((source.to_string(), token.get_src_line(), token.get_src_col()))
+}
+
+fn compile_ts_output(input_map: &str) -> (String, SourceMap<'static>) {
+    let output = compile_module(
+        JS_SOURCE,
+        "app.js",
+        ModuleType::Js,
+        Some(input_map),
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+    .unwrap();
+    let map = SourceMap::from_json_string(&output.map).unwrap().into_owned();
+    (output.code, map)
+}
+
+#[test]
+fn input_map_original_positions() {
+    let input_map = ts_input_map(&[
+        [0, 0, 0, 0],
+        [1, 0, 1, 0],
+        [1, 6, 1, 6],
+        [1, 10, 1, 18],
+        [2, 0, 2, 0],
+        [2, 13, 2, 13],
+        [2, 17, 2, 17],
+    ]);
+    let (code, map) = compile_ts_output(&input_map);
+    assert_eq!(map.get_sources().collect::<Vec<_>>(), ["app.ts"]);
+    assert_eq!(map.get_source_content(0), Some(TS_SOURCE));
+    assert_eq!(original_position(&code, &map, "n = 1"), Some(("app.ts".to_string(), 1, 6)));
+    assert_eq!(original_position(&code, &map, "1;"), Some(("app.ts".to_string(), 1, 18)));
+    assert_eq!(original_position(&code, &map, "a = "), Some(("app.ts".to_string(), 2, 13)));
+}
+
+#[test]
+fn input_map_unmapped_positions() {
+    // The second line doesn't have any mappings.
+    let input_map = ts_input_map(&[[0, 0, 0, 0], [2, 0, 2, 0], [2, 13, 2, 13]]);
+    let (code, map) = compile_ts_output(&input_map);
+    assert_eq!(original_position(&code, &map, "n = 1"), None);
+    assert_eq!(original_position(&code, &map, "a = "), Some(("app.ts".to_string(), 2, 13)));
+}
+
+const COMMENTS_SOURCE: &str = "\
+import { html } from \"ivi\";
+
+// a
+export const a = html`<div></div>`;
+const b = /* b */ html`<p>${a}</p>`;
+";
+
+fn compile_comments_output() -> (String, SourceMap<'static>) {
+    let output = compile_module(
+        COMMENTS_SOURCE,
+        "app.js",
+        ModuleType::Js,
+        None,
+        &CompilerOptions::default(),
+        &mut FxHashSet::default(),
+    )
+    .unwrap();
+    let map = SourceMap::from_json_string(&output.map).unwrap().into_owned();
+    (output.code, map)
+}
+
+#[test]
+fn generated_names() {
+    let (_, map) = compile_comments_output();
+    // Template source shouldn't be used as a name for generated identifiers.
+    assert_eq!(map.get_names().count(), 0);
+}
+
+#[test]
+fn template_declarations() {
+    let (code, map) = compile_comments_output();
+    assert!(code.contains("// a\nexport const a = _t(_TPL_);"));
+    assert!(!code.contains("/* b */ const _TPL_"));
+    assert_eq!(
+        original_position(&code, &map, "_T(/* @__PURE__ */ _hE(\"div\")"),
+        Some(("app.js".to_string(), 3, 17))
+    );
+    assert_eq!(
+        original_position(&code, &map, "_T(/* @__PURE__ */ _hE(\"p\")"),
+        Some(("app.js".to_string(), 4, 18))
+    );
+}
@@ -1,18 +1,119 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
-export declare class CompilerOutput {
+export declare class TemplateCompiler {
+  constructor(options?: CompilerOptions | undefined | null)
+  transform(sourceText: string, moduleType?: string | undefined | null, filename?: string | undefined | null, inputMap?: string | undefined | null): Promise<CompilerOutput>
+  buildStart(): void
+  renderStart(): void
+  renderChunk(sourceText: string, filename?: string | undefined | null, inputMap?: string | undefined | null): Promise<CompilerOutput>
+  report(): TemplateReport
+}
+
+export interface CompilerOutput {
   code: string
   map: string
+  warnings: Array<string>
+  templates?: Array<TemplateInfo>
 }
 
-export declare class TemplateCompiler {
-  constructor(options?: CompilerOptions | undefined | null)
-  transform(sourceText: string, moduleType: string): Promise<CompilerOutput>
-  renderStart(): void
-  renderChunk(sourceText: string): Promise<CompilerOutput>
+export interface TemplateInfo {
+  start: number
+  end: number
+  line: number
+  column: number
+  kind: "html" | "svg"
+  roots: number
+  blocks: Array<TemplateBlockInfo>
+}
+
+export interface TemplateBlockInfo {
+  element: boolean
+  staticsSize: number
+  stateSlots: number
+  childSlots: number
+  propOpCodes: number
+  childOpCodes: number
+  stateOpCodes: number
+  strings: Array<string>
+}
+
+export interface TemplateReport {
+  modules: number
+  templates: number
+  blocks: number
+  staticsSize: number
+  propOpCodes: OpCodesReport
+  childOpCodes: OpCodesReport
+  stateOpCodes: OpCodesReport
+  strings: StringsReport
+  moduleReports: Array<ModuleReport>
+  duplicates: Array<DuplicateReport>
+}
+
+export interface OpCodesReport {
+  arrays: number
+  opCodes: number
+}
+
+export interface StringsReport {
+  unique: number
+  references: number
+  deduped: number
+  savings: number
+}
+
+export interface ModuleReport {
+  file: string
+  templates: number
+  staticsSize: number
+  largest: Array<TemplateLocation>
+}
+
+export interface DuplicateReport {
+  staticsSize: number
+  locations: Array<TemplateLocation>
+}
+
+export interface TemplateLocation {
+  file: string
+  line: number
+  column: number
+  staticsSize: number
 }
 
 export interface CompilerOptions {
   dedupeStrings?: boolean
   oveo?: boolean
+  optimizer?: OptimizerOptions
+  iviModules?: Array<IviModule>
+  runtimeModule?: string
+  reExports?: Record<string, Record<string, "component" | "html" | "svg" | "List" | "useEffect" | "useAnimationFrameEffect" | "useIdleEffect">>
+  requirePrecompilation?: boolean
+  hoist?: HoistOptions
+  dev?: boolean
+  hmr?: boolean
+  sourcesContent?: boolean
+  warnings?: Record<"unsupported-template" | "form-state-attribute" | "suspicious-whitespace" | "inner-html" | "dynamic-import", "allow" | "warn" | "deny">
+  templates?: boolean
+  report?: boolean
+}
+
+export interface OptimizerOptions {
+  module?: string
+  hoist?: boolean
+  dedupe?: boolean
+  pure?: boolean
+}
+
+export interface HoistOptions {
+  components?: boolean
+  events?: boolean
+  directives?: boolean
+  list?: boolean
+  effects?: boolean
+}
+
+export interface IviModule {
+  specifier: string
+  exports?: Record<string, "component" | "html" | "svg" | "List" | "useEffect" | "useAnimationFrameEffect" | "useIdleEffect">
 }
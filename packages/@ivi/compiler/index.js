@@ -589,6 +589,5 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { CompilerOutput, TemplateCompiler } = nativeBinding
-export { CompilerOutput }
+const { TemplateCompiler } = nativeBinding
 export { TemplateCompiler }
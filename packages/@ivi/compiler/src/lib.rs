@@ -1,20 +1,160 @@
-use ivi_compiler::{compile_chunk, compile_module};
+use ivi_compiler::{
+    CompilerError, DiagnosticFormat, ModuleType, TemplateKind, compile_chunk, compile_module,
+};
 use napi::{Env, bindgen_prelude::*};
 use napi_derive::napi;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use std::sync::{Arc, Mutex, RwLock};
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    sync::{Arc, Mutex, RwLock},
+};
 
-#[napi]
+#[napi(object)]
 pub struct CompilerOutput {
     pub code: String,
     pub map: String,
+    pub warnings: Vec<String>,
+    pub templates: Option<Vec<TemplateInfo>>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct TemplateInfo {
+    pub start: u32,
+    pub end: u32,
+    pub line: u32,
+    pub column: u32,
+    #[napi(ts_type = "\"html\" | \"svg\"")]
+    pub kind: String,
+    pub roots: u32,
+    pub blocks: Vec<TemplateBlockInfo>,
+}
+
+#[napi(object)]
+#[derive(Clone)]
+pub struct TemplateBlockInfo {
+    pub element: bool,
+    pub statics_size: u32,
+    pub state_slots: u32,
+    pub child_slots: u32,
+    pub prop_op_codes: u32,
+    pub child_op_codes: u32,
+    pub state_op_codes: u32,
+    pub strings: Vec<String>,
+}
+
+// Summary of all templates that were compiled by the `transform()` method.
+#[napi(object)]
+pub struct TemplateReport {
+    pub modules: u32,
+    pub templates: u32,
+    pub blocks: u32,
+    // Total size of static HTML strings and tag names.
+    pub statics_size: u32,
+    pub prop_op_codes: OpCodesReport,
+    pub child_op_codes: OpCodesReport,
+    pub state_op_codes: OpCodesReport,
+    pub strings: StringsReport,
+    // Modules sorted by the size of static HTML strings.
+    pub module_reports: Vec<ModuleReport>,
+    // Template blocks with identical descriptors that are compiled in
+    // different modules.
+    pub duplicates: Vec<DuplicateReport>,
+}
+
+#[napi(object)]
+pub struct OpCodesReport {
+    // Non-empty opcode arrays.
+    pub arrays: u32,
+    pub op_codes: u32,
+}
+
+#[napi(object)]
+pub struct StringsReport {
+    pub unique: u32,
+    pub references: u32,
+    // Strings that are referenced by multiple template blocks.
+    pub deduped: u32,
+    // Size of duplicated string literals that are removed when strings are
+    // deduped. It is an upper bound, deduped strings are replaced with
+    // indices that are encoded into opcodes and can increase their size.
+    pub savings: u32,
+}
+
+#[napi(object)]
+pub struct ModuleReport {
+    pub file: String,
+    pub templates: u32,
+    pub statics_size: u32,
+    // The largest templates in the module.
+    pub largest: Vec<TemplateLocation>,
+}
+
+#[napi(object)]
+pub struct DuplicateReport {
+    pub statics_size: u32,
+    pub locations: Vec<TemplateLocation>,
+}
+
+#[napi(object)]
+pub struct TemplateLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub statics_size: u32,
 }
 
 #[napi(object)]
 pub struct CompilerOptions {
     pub dedupe_strings: Option<bool>,
     pub oveo: Option<bool>,
+    pub optimizer: Option<OptimizerOptions>,
+    pub ivi_modules: Option<Vec<IviModule>>,
+    pub runtime_module: Option<String>,
+    #[napi(
+        ts_type = "Record<string, Record<string, \"component\" | \"html\" | \"svg\" | \"List\" | \"useEffect\" | \"useAnimationFrameEffect\" | \"useIdleEffect\">>"
+    )]
+    pub re_exports: Option<HashMap<String, HashMap<String, String>>>,
+    pub require_precompilation: Option<bool>,
+    pub hoist: Option<HoistOptions>,
+    pub dev: Option<bool>,
+    pub hmr: Option<bool>,
+    pub sources_content: Option<bool>,
+    #[napi(
+        ts_type = "Record<\"unsupported-template\" | \"form-state-attribute\" | \"suspicious-whitespace\" | \"inner-html\" | \"dynamic-import\", \"allow\" | \"warn\" | \"deny\">"
+    )]
+    pub warnings: Option<HashMap<String, String>>,
+    pub templates: Option<bool>,
+    // Collects template info for the `report()` method.
+    pub report: Option<bool>,
+}
+
+#[napi(object)]
+pub struct OptimizerOptions {
+    pub module: Option<String>,
+    pub hoist: Option<bool>,
+    pub dedupe: Option<bool>,
+    pub pure: Option<bool>,
+}
+
+#[napi(object)]
+pub struct HoistOptions {
+    pub components: Option<bool>,
+    pub events: Option<bool>,
+    pub directives: Option<bool>,
+    pub list: Option<bool>,
+    pub effects: Option<bool>,
+}
+
+#[napi(object)]
+pub struct IviModule {
+    pub specifier: String,
+    #[napi(
+        ts_type = "Record<string, \"component\" | \"html\" | \"svg\" | \"List\" | \"useEffect\" | \"useAnimationFrameEffect\" | \"useIdleEffect\">"
+    )]
+    pub exports: Option<HashMap<String, String>>,
 }
 
 #[napi]
@@ -24,19 +164,87 @@ pub struct TemplateCompiler {
 
 struct CompilerState {
     options: ivi_compiler::CompilerOptions,
+    // Template info is returned from `transform()` only when it is enabled in
+    // the options, and it is collected for reports when reports are enabled.
+    output_templates: bool,
+    report: bool,
     unique_strings: Mutex<FxHashSet<String>>,
     indexed_strings: RwLock<FxHashMap<String, u8>>,
+    templates: Mutex<FxHashMap<String, Vec<ivi_compiler::TemplateInfo>>>,
 }
 
+// The number of the largest templates in module reports.
+const REPORT_LARGEST_TEMPLATES: usize = 5;
+
 #[napi]
 impl TemplateCompiler {
     #[napi(constructor)]
     pub fn new(options: Option<CompilerOptions>) -> Result<Self> {
+        let output_templates = options.as_ref().and_then(|o| o.templates).unwrap_or(false);
+        let report = options.as_ref().and_then(|o| o.report).unwrap_or(false);
         let options = if let Some(options) = options {
-            ivi_compiler::CompilerOptions {
-                oveo: options.oveo.unwrap_or(false),
-                dedupe_strings: options.dedupe_strings.unwrap_or(false),
+            let mut optimizer = if options.oveo.unwrap_or(false) {
+                ivi_compiler::Optimizer::oveo()
+            } else {
+                ivi_compiler::Optimizer::default()
+            };
+            if let Some(o) = options.optimizer {
+                if let Some(module) = o.module {
+                    optimizer.module = module;
+                }
+                optimizer.hoist = o.hoist.unwrap_or(optimizer.hoist);
+                optimizer.dedupe = o.dedupe.unwrap_or(optimizer.dedupe);
+                optimizer.pure = o.pure.unwrap_or(optimizer.pure);
+            }
+            let mut builder = ivi_compiler::CompilerOptions::builder()
+                .optimizer(optimizer)
+                .dedupe_strings(options.dedupe_strings.unwrap_or(false))
+                .require_precompilation(options.require_precompilation.unwrap_or(false))
+                .dev(options.dev.unwrap_or(false))
+                .hmr(options.hmr.unwrap_or(false))
+                .sources_content(options.sources_content.unwrap_or(true))
+                .templates(output_templates || report);
+            if let Some(modules) = options.ivi_modules {
+                builder = builder
+                    .ivi_modules(modules.into_iter().map(ivi_module).collect::<Result<Vec<_>>>()?);
+            }
+            if let Some(hoist) = options.hoist {
+                let d = ivi_compiler::HoistOptions::default();
+                builder = builder.hoist(ivi_compiler::HoistOptions {
+                    components: hoist.components.unwrap_or(d.components),
+                    events: hoist.events.unwrap_or(d.events),
+                    directives: hoist.directives.unwrap_or(d.directives),
+                    list: hoist.list.unwrap_or(d.list),
+                    effects: hoist.effects.unwrap_or(d.effects),
+                });
+            }
+            if let Some(runtime_module) = options.runtime_module {
+                builder = builder.runtime_module(runtime_module);
+            }
+            if let Some(warnings) = options.warnings {
+                for (code, level) in warnings {
+                    let Some(code) = ivi_compiler::WarningCode::from_name(&code) else {
+                        return Err(Error::from_reason(format!("Invalid warning code '{code}'")));
+                    };
+                    let Some(level) = ivi_compiler::WarningLevel::from_name(&level) else {
+                        return Err(Error::from_reason(format!(
+                            "Invalid warning level '{level}' for the '{}' warning",
+                            code.as_str()
+                        )));
+                    };
+                    builder = builder.warning(code, level);
+                }
             }
+            if let Some(re_exports) = options.re_exports {
+                for (path, exports) in re_exports {
+                    let path = std::path::absolute(&path).map_err(|err| {
+                        Error::from_reason(format!("Invalid path '{path}': {err}"))
+                    })?;
+                    let exports = ivi_exports(&path.to_string_lossy(), exports)?;
+                    builder = builder.re_export(path, exports);
+                }
+            }
+            builder.build()
         } else {
             ivi_compiler::CompilerOptions::default()
         };
@@ -44,20 +252,47 @@ impl TemplateCompiler {
         Ok(Self {
             inner: Arc::new(CompilerState {
                 options,
+                output_templates,
+                report,
                 unique_strings: Mutex::default(),
                 indexed_strings: RwLock::default(),
+                templates: Mutex::default(),
             }),
         })
     }
 
+    // Module type is inferred from the filename when it isn't specified.
     #[napi(ts_return_type = "Promise<CompilerOutput>")]
-    pub fn transform(&self, source_text: String, module_type: String) -> AsyncTask<TransformTask> {
-        AsyncTask::new(TransformTask {
+    pub fn transform(
+        &self,
+        source_text: String,
+        module_type: Option<String>,
+        filename: Option<String>,
+        input_map: Option<String>,
+    ) -> Result<AsyncTask<TransformTask>> {
+        let module_type = match (module_type, &filename) {
+            (Some(module_type), _) => module_type
+                .parse::<ModuleType>()
+                .map_err(|err| Error::from_reason(err.to_string()))?,
+            // Modules with unknown extensions (virtual modules, etc) are
+            // parsed as tsx modules.
+            (None, Some(filename)) => ModuleType::from_path(filename).unwrap_or(ModuleType::Tsx),
+            (None, None) => ModuleType::Tsx,
+        };
+        Ok(AsyncTask::new(TransformTask {
             compiler: Arc::clone(&self.inner),
             source_text,
             module_type,
+            filename: filename.unwrap_or_default(),
+            input_map,
             dedupe_strings: self.inner.options.dedupe_strings,
-        })
+        }))
+    }
+
+    // Removes template info that was collected in previous builds.
+    #[napi]
+    pub fn build_start(&self) {
+        self.inner.templates.lock().unwrap().clear();
     }
 
     #[napi]
@@ -74,15 +309,183 @@ impl TemplateCompiler {
     }
 
     #[napi(ts_return_type = "Promise<CompilerOutput>")]
-    pub fn render_chunk(&self, source_text: String) -> AsyncTask<RenderChunkTask> {
-        AsyncTask::new(RenderChunkTask { compiler: Arc::clone(&self.inner), source_text })
+    pub fn render_chunk(
+        &self,
+        source_text: String,
+        filename: Option<String>,
+        input_map: Option<String>,
+    ) -> AsyncTask<RenderChunkTask> {
+        AsyncTask::new(RenderChunkTask {
+            compiler: Arc::clone(&self.inner),
+            source_text,
+            filename: filename.unwrap_or_default(),
+            input_map,
+        })
+    }
+
+    #[napi]
+    pub fn report(&self) -> Result<TemplateReport> {
+        if !self.inner.report {
+            return Err(Error::from_reason(
+                "Template reports are disabled, they should be enabled with the `report` option",
+            ));
+        }
+        let modules = self.inner.templates.lock().unwrap();
+        let mut report = TemplateReport {
+            modules: 0,
+            templates: 0,
+            blocks: 0,
+            statics_size: 0,
+            prop_op_codes: OpCodesReport { arrays: 0, op_codes: 0 },
+            child_op_codes: OpCodesReport { arrays: 0, op_codes: 0 },
+            state_op_codes: OpCodesReport { arrays: 0, op_codes: 0 },
+            strings: StringsReport { unique: 0, references: 0, deduped: 0, savings: 0 },
+            module_reports: Vec::new(),
+            duplicates: Vec::new(),
+        };
+        let mut strings = FxHashMap::<&str, u32>::default();
+        // Blocks are equal when they are compiled into identical descriptors.
+        let mut descriptors =
+            FxHashMap::<&ivi_compiler::TemplateBlockInfo, Vec<TemplateLocation>>::default();
+
+        let mut files: Vec<_> = modules.iter().filter(|(_, t)| !t.is_empty()).collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (file, templates) in files {
+            let mut locations = Vec::with_capacity(templates.len());
+            for t in templates {
+                let mut statics_size = 0;
+                for b in &t.blocks {
+                    statics_size += b.statics_size;
+                    for (r, len) in [
+                        (&mut report.prop_op_codes, b.prop_op_codes),
+                        (&mut report.child_op_codes, b.child_op_codes),
+                        (&mut report.state_op_codes, b.state_op_codes),
+                    ] {
+                        if len > 0 {
+                            r.arrays += 1;
+                            r.op_codes += len;
+                        }
+                    }
+                    for s in &b.strings {
+                        *strings.entry(s).or_default() += 1;
+                    }
+                    let descriptor = descriptors.entry(b).or_default();
+                    // Identical blocks in the same module are deduped by
+                    // the module-level hoisting.
+                    if !descriptor.iter().any(|l| l.file == *file) {
+                        descriptor.push(TemplateLocation {
+                            file: file.clone(),
+                            line: t.line,
+                            column: t.column,
+                            statics_size: b.statics_size,
+                        });
+                    }
+                }
+                report.blocks += t.blocks.len() as u32;
+                locations.push(TemplateLocation {
+                    file: file.clone(),
+                    line: t.line,
+                    column: t.column,
+                    statics_size,
+                });
+            }
+
+            let module_statics_size = locations.iter().map(|l| l.statics_size).sum();
+            report.modules += 1;
+            report.templates += templates.len() as u32;
+            report.statics_size += module_statics_size;
+            locations.sort_by_key(|l| Reverse(l.statics_size));
+            locations.truncate(REPORT_LARGEST_TEMPLATES);
+            report.module_reports.push(ModuleReport {
+                file: file.clone(),
+                templates: templates.len() as u32,
+                statics_size: module_statics_size,
+                largest: locations,
+            });
+        }
+        report.module_reports.sort_by_key(|m| Reverse(m.statics_size));
+
+        for (s, n) in strings {
+            report.strings.unique += 1;
+            report.strings.references += n;
+            if n > 1 {
+                report.strings.deduped += 1;
+                // String literal with quotes and a comma separator, sizes of
+                // string indices aren't subtracted.
+                report.strings.savings += (n - 1) * (s.len() as u32 + 3);
+            }
+        }
+
+        report.duplicates = descriptors
+            .into_values()
+            .filter(|locations| locations.len() > 1)
+            .map(|locations| DuplicateReport { statics_size: locations[0].statics_size, locations })
+            .collect();
+        report.duplicates.sort_by(|a, b| {
+            let size = |d: &DuplicateReport| d.statics_size * (d.locations.len() as u32 - 1);
+            size(b).cmp(&size(a)).then_with(|| a.locations[0].file.cmp(&b.locations[0].file))
+        });
+
+        Ok(report)
+    }
+}
+
+fn compiler_output(
+    output: ivi_compiler::CompilerOutput,
+    filename: &str,
+    source_text: &str,
+) -> CompilerOutput {
+    CompilerOutput {
+        code: output.code,
+        map: output.map,
+        warnings: output
+            .diagnostics
+            .iter()
+            .map(|d| d.render(filename, source_text, DiagnosticFormat::Graphical))
+            .collect(),
+        templates: output.templates.map(|templates| {
+            templates
+                .into_iter()
+                .map(|t| TemplateInfo {
+                    start: t.start,
+                    end: t.end,
+                    line: t.line,
+                    column: t.column,
+                    kind: match t.kind {
+                        TemplateKind::Html => "html".to_string(),
+                        TemplateKind::Svg => "svg".to_string(),
+                    },
+                    roots: t.roots,
+                    blocks: t
+                        .blocks
+                        .into_iter()
+                        .map(|b| TemplateBlockInfo {
+                            element: b.element,
+                            statics_size: b.statics_size,
+                            state_slots: b.state_slots,
+                            child_slots: b.child_slots,
+                            prop_op_codes: b.prop_op_codes,
+                            child_op_codes: b.child_op_codes,
+                            state_op_codes: b.state_op_codes,
+                            strings: b.strings,
+                        })
+                        .collect(),
+                })
+                .collect()
+        }),
     }
 }
 
+fn compiler_error(err: CompilerError, filename: &str, source_text: &str) -> Error {
+    Error::from_reason(err.render(filename, source_text, DiagnosticFormat::Graphical))
+}
+
 pub struct TransformTask {
     compiler: Arc<CompilerState>,
     source_text: String,
-    module_type: String,
+    module_type: ModuleType,
+    filename: String,
+    input_map: Option<String>,
     dedupe_strings: bool,
 }
 
@@ -92,14 +495,29 @@ impl Task for TransformTask {
 
     fn compute(&mut self) -> Result<Self::Output> {
         let mut strings = FxHashSet::default();
-        let result = compile_module(
+        let mut output = compile_module(
             &self.source_text,
-            &self.module_type,
+            &self.filename,
+            self.module_type,
+            self.input_map.as_deref(),
             &self.compiler.options,
             &mut strings,
         )
-        .map(|v| CompilerOutput { code: v.code, map: v.map })
-        .map_err(|err| Error::from_reason(err.to_string()))?;
+        .map_err(|err| compiler_error(err, &self.filename, &self.source_text))?;
+
+        // Anonymous modules can't be distinguished from each other, so they
+        // aren't included in reports.
+        if self.compiler.report
+            && !self.filename.is_empty()
+            && let Some(templates) = &output.templates
+        {
+            let mut modules = self.compiler.templates.lock().unwrap();
+            modules.insert(self.filename.clone(), templates.clone());
+        }
+        if !self.compiler.output_templates {
+            output.templates = None;
+        }
+        let result = compiler_output(output, &self.filename, &self.source_text);
 
         if self.dedupe_strings && !strings.is_empty() {
             let mut unique = self.compiler.unique_strings.lock().unwrap();
@@ -117,6 +535,8 @@ impl Task for TransformTask {
 pub struct RenderChunkTask {
     compiler: Arc<CompilerState>,
     source_text: String,
+    filename: String,
+    input_map: Option<String>,
 }
 
 impl Task for RenderChunkTask {
@@ -126,12 +546,42 @@ impl Task for RenderChunkTask {
     fn compute(&mut self) -> Result<Self::Output> {
         let strings = self.compiler.indexed_strings.read().unwrap();
 
-        compile_chunk(&self.source_text, &strings)
-            .map(|v| CompilerOutput { code: v.code, map: v.map })
-            .map_err(|err| Error::from_reason(err.to_string()))
+        compile_chunk(
+            &self.source_text,
+            &self.filename,
+            self.input_map.as_deref(),
+            &self.compiler.options,
+            &strings,
+        )
+        .map(|v| compiler_output(v, &self.filename, &self.source_text))
+        .map_err(|err| compiler_error(err, &self.filename, &self.source_text))
     }
 
     fn resolve(&mut self, _env: Env, output: CompilerOutput) -> Result<Self::JsValue> {
         Ok(output)
     }
 }
+
+fn ivi_module(module: IviModule) -> Result<ivi_compiler::IviModule> {
+    if let Some(exports) = module.exports {
+        let exports = ivi_exports(&module.specifier, exports)?;
+        Ok(ivi_compiler::IviModule::with_exports(module.specifier, exports))
+    } else {
+        Ok(ivi_compiler::IviModule::new(module.specifier))
+    }
+}
+
+fn ivi_exports(
+    module: &str,
+    exports: HashMap<String, String>,
+) -> Result<Vec<(String, ivi_compiler::IviExport)>> {
+    exports
+        .into_iter()
+        .map(|(name, export)| match ivi_compiler::IviExport::from_name(&export) {
+            Some(export) => Ok((name, export)),
+            None => Err(Error::from_reason(format!(
+                "Invalid export '{export}' in the '{module}' module"
+            ))),
+        })
+        .collect()
+}
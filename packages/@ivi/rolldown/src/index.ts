@@ -1,27 +1,50 @@
-import { TemplateCompiler, type CompilerOptions } from "@ivi/compiler";
+import { TemplateCompiler, type CompilerOptions, type TemplateReport } from "@ivi/compiler";
 import type { HookFilter, RolldownPlugin } from "rolldown";
 
 export interface IviOptions extends CompilerOptions {
   readonly filter?: HookFilter,
+  /**
+   * Invoked with a template report when a bundle is generated, enables the
+   * `report` option.
+   */
+  readonly onReport?: (report: TemplateReport) => void,
 }
 
 export function ivi(options: IviOptions = {}): RolldownPlugin {
   const compiler = new TemplateCompiler({
     dedupeStrings: options.dedupeStrings ?? true,
     oveo: options.oveo ?? false,
+    iviModules: options.iviModules,
+    runtimeModule: options.runtimeModule,
+    reExports: options.reExports,
+    requirePrecompilation: options.requirePrecompilation,
+    optimizer: options.optimizer,
+    hoist: options.hoist,
+    dev: options.dev,
+    hmr: options.hmr,
+    sourcesContent: options.sourcesContent,
+    warnings: options.warnings,
+    report: options.report ?? options.onReport !== void 0,
   });
   return {
     name: "ivi",
 
     transform: {
       filter: {
-        code: "ivi",
+        // Modules that import templates from local re-exporting modules don't
+        // have ivi module specifiers.
+        ...(options.reExports ? {} : {
+          code: options.iviModules?.map((m) => m.specifier) ?? "ivi",
+        }),
         moduleType: ["js", "jsx", "ts", "tsx"],
         ...options.filter,
       },
-      async handler(code: string, _id: string, { moduleType }) {
+      async handler(code: string, id: string, { moduleType }) {
         try {
-          const result = await compiler.transform(code, moduleType);
+          const result = await compiler.transform(code, moduleType, id);
+          for (const warning of result.warnings) {
+            this.warn(warning);
+          }
           const map = result.map;
           code = result.code;
           return map ? { code, map } : { code };
@@ -31,6 +54,10 @@ export function ivi(options: IviOptions = {}): RolldownPlugin {
       },
     },
 
+    buildStart() {
+      compiler.buildStart();
+    },
+
     renderStart() {
       compiler.renderStart();
     },
@@ -39,9 +66,9 @@ export function ivi(options: IviOptions = {}): RolldownPlugin {
       filter: {
         code: "IVI",
       },
-      async handler(code, _chunk) {
+      async handler(code, chunk) {
         try {
-          const result = await compiler.renderChunk(code);
+          const result = await compiler.renderChunk(code, chunk.fileName);
           const map = result.map;
           code = result.code;
           return map ? { code, map } : { code };
@@ -50,5 +77,9 @@ export function ivi(options: IviOptions = {}): RolldownPlugin {
         }
       },
     },
+
+    generateBundle() {
+      options.onReport?.(compiler.report());
+    },
   };
 }
@@ -1,8 +1,13 @@
-import { TemplateCompiler, type CompilerOptions } from "@ivi/compiler";
+import { TemplateCompiler, type CompilerOptions, type TemplateReport } from "@ivi/compiler";
 import type { HookFilter, Plugin } from "rollup";
 
 export interface IviOptions extends CompilerOptions {
   readonly filter?: HookFilter,
+  /**
+   * Invoked with a template report when a bundle is generated, enables the
+   * `report` option.
+   */
+  readonly onReport?: (report: TemplateReport) => void,
 }
 
 export function ivi(options: IviOptions = {}): Plugin & { config(options: any, env: { mode: string; command: string; }): void; } {
@@ -13,24 +18,44 @@ export function ivi(options: IviOptions = {}): Plugin & { config(options: any, e
     config(_options, { command }) {
       let dedupeStrings = options?.dedupeStrings ?? true;
       let oveo = options?.oveo ?? false;
+      let optimizer = options.optimizer;
       if (command !== "build") {
         dedupeStrings = false;
         oveo = false;
+        optimizer = undefined;
       }
       compiler = new TemplateCompiler({
         dedupeStrings,
         oveo,
+        optimizer,
+        iviModules: options.iviModules,
+        runtimeModule: options.runtimeModule,
+        reExports: options.reExports,
+        requirePrecompilation: options.requirePrecompilation,
+        hoist: options.hoist,
+        dev: options.dev,
+        hmr: options.hmr,
+        sourcesContent: options.sourcesContent,
+        warnings: options.warnings,
+        report: options.report ?? options.onReport !== void 0,
       });
     },
 
     transform: {
       filter: {
-        code: "ivi",
+        // Modules that import templates from local re-exporting modules don't
+        // have ivi module specifiers.
+        ...(options.reExports ? {} : {
+          code: options.iviModules?.map((m) => m.specifier) ?? "ivi",
+        }),
         ...options.filter,
       },
       async handler(code: string, id: string) {
         try {
-          const result = await compiler.transform(code, "tsx");
+          const result = await compiler.transform(code, null, id);
+          for (const warning of result.warnings) {
+            this.warn(warning);
+          }
           const map = result.map;
           code = result.code;
           return map ? { code, map } : { code };
@@ -40,18 +65,22 @@ export function ivi(options: IviOptions = {}): Plugin & { config(options: any, e
       },
     },
 
+    buildStart() {
+      compiler.buildStart();
+    },
+
     renderStart() {
       compiler.renderStart();
     },
 
-    async renderChunk(code, _chunk) {
+    async renderChunk(code, chunk) {
       // Fast-path for chunks that doesn't have any ivi code.
       if (!code.includes("IVI")) {
         return;
       }
 
       try {
-        const result = await compiler.renderChunk(code);
+        const result = await compiler.renderChunk(code, chunk.fileName);
         const map = result.map;
         code = result.code;
         return map ? { code, map } : { code };
@@ -59,5 +88,9 @@ export function ivi(options: IviOptions = {}): Plugin & { config(options: any, e
         this.error(`Failed to render chunk: ${err}`);
       }
     },
+
+    generateBundle() {
+      options.onReport?.(compiler.report());
+    },
   };
 }
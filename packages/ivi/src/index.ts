@@ -6,8 +6,8 @@ export {
   // Stateless Nodes
   type VAny, type VRoot, type VTemplate, type VComponent, type VList,
   // Template
-  type TemplateDescriptor, type ElementDirective,
-  _hN, _hE, _sN, _sE, _T, _t,
+  type TemplateDescriptor, type ElementDirective, type ElementRef,
+  _hN, _hE, _hD, _sN, _sE, _sD, _T, _Td, _t, _b, _dev,
   // Components
   type ComponentFactory, type Effect,
   component, getProps, invalidate,
@@ -33,4 +33,6 @@ export {
 export {
   preventUpdates, strictEq, shallowEq, shallowEqArray,
 } from "./lib/equal.js";
+export { type TemplateDebugInfo } from "./lib/template.js";
+export { _hmrT, _hmrC } from "./lib/hmr.js";
 export { html, svg } from "./html/index.js";
\ No newline at end of file
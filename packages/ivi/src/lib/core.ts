@@ -1,6 +1,7 @@
 import {
-  type TemplateData,
+  type TemplateData, type TemplateDebugInfo,
   TemplateFlags, ChildOpCode, PropOpCode, StateOpCode, CommonPropType,
+  EventModifier,
 } from "./template.js";
 
 export const EMPTY_ARRAY: any[] = [];
@@ -331,6 +332,15 @@ export interface ListProps<K = any> {
  */
 export type ElementDirective = <E extends Element>(element: E) => void;
 
+/**
+ * Element Ref.
+ *
+ * Ref is assigned only once when template is created.
+ */
+export type ElementRef<E extends Element = Element> =
+  | ((element: E) => void)
+  | { current: E | null };
+
 export const _flushDOMEffects = () => {
   const e = RENDER_CONTEXT.e;
   if (e.length > 0) {
@@ -341,6 +351,42 @@ export const _flushDOMEffects = () => {
   }
 };
 
+/**
+ * Handler wrappers for event modifiers that can't be expressed with listener
+ * options. Wrappers are cached so that the same handler can be removed with
+ * `removeEventListener`.
+ */
+const EVENT_HANDLER_WRAPPERS = new WeakMap<(ev: Event) => void, ((ev: Event) => void)[]>();
+
+const _eventHandler = (
+  handler: (ev: Event) => void,
+  modifiers: number,
+): (ev: Event) => void => {
+  if ((modifiers & EventModifier.WrapMask) === 0) {
+    return handler;
+  }
+  let wrappers = EVENT_HANDLER_WRAPPERS.get(handler);
+  if (wrappers === void 0) {
+    EVENT_HANDLER_WRAPPERS.set(handler, wrappers = []);
+  }
+  let wrapper = wrappers[modifiers];
+  if (wrapper === void 0) {
+    wrappers[modifiers] = wrapper = (ev: Event) => {
+      if ((modifiers & EventModifier.Self) !== 0 && ev.target !== ev.currentTarget) {
+        return;
+      }
+      if ((modifiers & EventModifier.Prevent) !== 0) {
+        ev.preventDefault();
+      }
+      if ((modifiers & EventModifier.Stop) !== 0) {
+        ev.stopPropagation();
+      }
+      handler(ev);
+    };
+  }
+  return wrapper;
+};
+
 const _updateTemplateProperties = (
   currentElement: Element,
   opCodes: PropOpCode[],
@@ -395,12 +441,20 @@ const _updateTemplateProperties = (
               } else if (prev != null && prev !== "" && prev !== false) {
                 nodeSetTextContent.call(currentElement, "");
               }
-            } else { // CommonPropType.InnerHTML
+            } else if (dataIndex === CommonPropType.InnerHTML) {
               if (next !== "" && next != null && next !== false) {
                 elementSetInnerHTML.call(currentElement, next);
               } else if (prev !== "" && prev != null && prev !== false) {
                 nodeSetTextContent.call(currentElement, "");
               }
+            } else { // CommonPropType.Ref
+              if (prevProps === null && next != null) {
+                if (typeof next === "function") {
+                  (next as (element: Element) => void)(currentElement);
+                } else {
+                  (next as { current: Element | null }).current = currentElement;
+                }
+              }
             }
           } else if (type === PropOpCode.Directive) {
             (next as ElementDirective)(currentElement);
@@ -431,11 +485,37 @@ const _updateTemplateProperties = (
                 style!.removeProperty(key);
               }
             } else { // PropOpCode.Event
-              if (prev != null && prev !== false) {
-                elementRemoveEventListener.call(currentElement, key, prev);
-              }
-              if (next != null && next !== false) {
-                elementAddEventListener.call(currentElement, key, next);
+              const modifiers = dataIndex & EventModifier.Mask6;
+              const eventKey = data[dataIndex >> EventModifier.KeyShift];
+              if (modifiers === 0) {
+                if (prev != null && prev !== false) {
+                  elementRemoveEventListener.call(currentElement, eventKey, prev);
+                }
+                if (next != null && next !== false) {
+                  elementAddEventListener.call(currentElement, eventKey, next);
+                }
+              } else {
+                const capture = (modifiers & EventModifier.Capture) !== 0;
+                if (prev != null && prev !== false) {
+                  elementRemoveEventListener.call(
+                    currentElement,
+                    eventKey,
+                    _eventHandler(prev, modifiers),
+                    capture,
+                  );
+                }
+                if (next != null && next !== false) {
+                  elementAddEventListener.call(
+                    currentElement,
+                    eventKey,
+                    _eventHandler(next, modifiers),
+                    {
+                      capture,
+                      passive: (modifiers & EventModifier.Passive) !== 0,
+                      once: (modifiers & EventModifier.Once) !== 0,
+                    },
+                  );
+                }
               }
             }
           }
@@ -537,6 +617,67 @@ const _updateArray = (
   return sNode;
 };
 
+/**
+ * Invokes a function that mounts or updates a template and adds template
+ * source location to thrown errors.
+ *
+ * Source location is attached to templates only in dev mode, other templates
+ * are mounted and updated without try/catch blocks.
+ *
+ * @param m Template source information.
+ * @param fn Function that mounts or updates a template.
+ * @param args Function arguments.
+ * @returns Function result.
+ */
+const _devTemplate = <A extends any[], R>(
+  m: TemplateDebugInfo,
+  fn: (...args: A) => R,
+  ...args: A
+): R => {
+  try {
+    return fn(...args);
+  } catch (e) {
+    throw Error(`Error in template at ${m.file}:${m.line}:${m.column}`, { cause: e });
+  }
+};
+
+/**
+ * Instantiates a template and assigns its properties.
+ *
+ * @param descriptor Template descriptor.
+ * @param props Template properties.
+ * @returns Template state, the first node is the root DOM node.
+ */
+const _instantiateTemplate = (descriptor: TemplateDescriptor, props: any[]): Node[] => {
+  const tplData = descriptor.p1;
+  const stateOpCodes = tplData.s;
+  const flags = tplData.f;
+  const rootDOMNode = descriptor.p2();
+  const state = _Array<Node>(flags & TemplateFlags.Mask6);
+  state[0] = rootDOMNode;
+
+  if (stateOpCodes.length > 0) {
+    RENDER_CONTEXT.si = 0;
+    _assignTemplateSlots(
+      nodeGetFirstChild.call(rootDOMNode)!,
+      stateOpCodes,
+      0,
+      stateOpCodes.length,
+      state,
+    );
+  }
+  _updateTemplateProperties(
+    rootDOMNode,
+    tplData.p,
+    tplData.d,
+    state,
+    null,
+    props,
+    !!(flags & TemplateFlags.Svg),
+  );
+  return state;
+};
+
 /**
  * Updates a Stateful Node with a new Stateless Node.
  *
@@ -644,15 +785,30 @@ const _update = (
       elementMoveBefore!.call(parentElement, rootDOMNode, ctx.n);
     }
 
-    _updateTemplateProperties(
-      rootDOMNode,
-      propsOpCodes,
-      data,
-      state as Node[],
-      prevProps,
-      nextProps,
-      !!(flags & TemplateFlags.Svg),
-    );
+    const m = tplData.m;
+    if (m === void 0) {
+      _updateTemplateProperties(
+        rootDOMNode,
+        propsOpCodes,
+        data,
+        state as Node[],
+        prevProps,
+        nextProps,
+        !!(flags & TemplateFlags.Svg),
+      );
+    } else {
+      _devTemplate(
+        m,
+        _updateTemplateProperties,
+        rootDOMNode,
+        propsOpCodes,
+        data,
+        state as Node[],
+        prevProps,
+        nextProps,
+        !!(flags & TemplateFlags.Svg),
+      );
+    }
 
     if (children !== null) {
       ctx.p = rootDOMNode;
@@ -727,34 +883,13 @@ const _mount = (parentSNode: SNode, v: VAny): SNode | null => {
           const parentDOMElement = ctx.p;
           const nextDOMNode = ctx.n;
           const tplData = descriptorP1 as TemplateData;
-          const data = tplData.d;
-          const propsOpCodes = tplData.p;
-          const stateOpCodes = tplData.s;
           const childOpCodes = tplData.c;
           const flags = tplData.f;
-          const rootDOMNode = (descriptor as TemplateDescriptor).p2();
-          const state = _Array<Node>(flags & TemplateFlags.Mask6);
-          state[0] = rootDOMNode;
-
-          if (stateOpCodes.length > 0) {
-            ctx.si = 0;
-            _assignTemplateSlots(
-              nodeGetFirstChild.call(rootDOMNode)!,
-              stateOpCodes,
-              0,
-              stateOpCodes.length,
-              state,
-            );
-          }
-          _updateTemplateProperties(
-            rootDOMNode,
-            propsOpCodes,
-            data,
-            state,
-            null,
-            props,
-            !!(flags & TemplateFlags.Svg),
-          );
+          const m = tplData.m;
+          const state = m === void 0
+            ? _instantiateTemplate(descriptor as TemplateDescriptor, props)
+            : _devTemplate(m, _instantiateTemplate, descriptor as TemplateDescriptor, props);
+          const rootDOMNode = state[0] as Element;
 
           const sNode = createSNode(
             Flags.Template,
@@ -1437,6 +1572,40 @@ export const _hE = (t: string): () => Element => (
   () => doc.createElement(t)
 );
 
+/**
+ * Tag names are inserted into template strings, so they should be validated to
+ * prevent HTML injection.
+ */
+const TAG_NAME = /^[a-zA-Z][a-zA-Z0-9._:-]*$/;
+
+const checkTagName = (tag: string): string => {
+  if (!TAG_NAME.test(tag)) {
+    throw Error(`Invalid tag name: ${JSON.stringify(tag)}`);
+  }
+  return tag;
+};
+
+const VOID_ELEMENTS = /^(audio|video|embed|input|param|source|track|area|base|link|meta|br|col|hr|img|wbr)$/;
+
+/**
+ * Creates a HTML Template cloning factory for elements with dynamic tag names.
+ *
+ * Static templates of elements without children don't have a closing tag:
+ * `["<", ">"]`, so that they could be used with void elements.
+ *
+ * @__NO_SIDE_EFFECTS__
+ */
+export const _hD = (s: string[]) => (tag: string): () => Element => {
+  checkTagName(tag);
+  if (VOID_ELEMENTS.test(tag)) {
+    if (s.length > 2) {
+      throw Error(`Void element '${tag}' can't have children`);
+    }
+    return _hN(s[0] + tag + s[1]);
+  }
+  return _hN(s.length > 2 ? s.join(tag) : `${s[0]}${tag}${s[1]}</${tag}>`);
+};
+
 /**
  * Creates a SVG Template cloning factory.
  */
@@ -1459,6 +1628,13 @@ export const _sE = (t: string): () => Element => (
   () => doc.createElementNS("http://www.w3.org/2000/svg", t)
 );
 
+/**
+ * Creates a SVG Template cloning factory for elements with dynamic tag names.
+ *
+ * @__NO_SIDE_EFFECTS__
+ */
+export const _sD = (s: string[]) => (tag: string): () => Element => _sN(s.join(checkTagName(tag)));
+
 /**
  * Creates a template descriptor with globally shared data.
  * 
@@ -1477,6 +1653,83 @@ export const _T = (
   p2,
 });
 
+/**
+ * Creates a template descriptor factory for elements with dynamic tag names.
+ *
+ * Template descriptors are cached for each tag name, so that templates with
+ * the same tag name will have the same identity.
+ *
+ * @__NO_SIDE_EFFECTS__
+ */
+export const _Td = (
+  p2: (tag: string) => () => Element,
+  f: number,
+  p: PropOpCode[],
+  c: ChildOpCode[],
+  s: StateOpCode[],
+  d = __IVI_STRINGS__,
+): (tag: string) => TemplateDescriptor => {
+  const data = { f, p, c, s, d };
+  const descriptors = new _Map<string, TemplateDescriptor>();
+  return (tag) => {
+    let descriptor = descriptors.get(tag);
+    if (descriptor === void 0) {
+      descriptors.set(tag, descriptor = {
+        f: Flags.Template,
+        p1: data,
+        p2: p2(tag),
+      });
+    }
+    return descriptor;
+  };
+};
+
+/**
+ * Attaches template source information to a template descriptor.
+ *
+ * @__NO_SIDE_EFFECTS__
+ */
+export const _dev = <T extends TemplateDescriptor | ((tag: string) => TemplateDescriptor)>(
+  d: T,
+  m: TemplateDebugInfo,
+): T => {
+  if (typeof d === "function") {
+    const factory = d as (tag: string) => TemplateDescriptor;
+    return ((tag: string) => {
+      const descriptor = factory(tag);
+      descriptor.p1.m = m;
+      return descriptor;
+    }) as T;
+  }
+  (d as TemplateDescriptor).p1.m = m;
+  return d;
+};
+
+/**
+ * Event handlers for two-way bindings `bind:key=${[get, set]}`.
+ *
+ * Handlers are cached, so that they will have the same identity when setter
+ * function doesn't change.
+ */
+const BINDING_HANDLERS = new WeakMap<(v: any) => void, Record<string, (ev: Event) => void>>();
+
+/**
+ * Creates an event handler for a two-way binding.
+ */
+export const _b = (set: (v: any) => void, key: string): (ev: Event) => void => {
+  let handlers = BINDING_HANDLERS.get(set);
+  if (handlers === void 0) {
+    BINDING_HANDLERS.set(set, handlers = {});
+  }
+  let handler = handlers[key];
+  if (handler === void 0) {
+    handlers[key] = handler = (ev: Event) => {
+      set((ev.target as Record<string, any>)[key]);
+    };
+  }
+  return handler;
+};
+
 /**
  * @__NO_SIDE_EFFECTS__
  */
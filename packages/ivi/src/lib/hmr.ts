@@ -0,0 +1,97 @@
+import {
+  type Component, type ComponentDescriptor, type ComponentFactoryFn,
+  type VComponent,
+  invalidate, useUnmount,
+} from "./core.js";
+
+/**
+ * Hot Template.
+ */
+interface HotTemplate {
+  /** Hash of the template source text. */
+  h: string,
+  /** Template descriptor. */
+  d: any,
+}
+
+/**
+ * Hot Component.
+ */
+interface HotComponent {
+  /** Component factory that is shared by all component versions. */
+  c: (p?: any) => VComponent,
+  /** Component descriptor that is shared by all component versions. */
+  d: ComponentDescriptor,
+  /** Latest component factory function. */
+  f: ComponentFactoryFn,
+  /** Mounted component instances. */
+  i: Set<Component>,
+}
+
+const HOT_TEMPLATES = new Map<string, HotTemplate>();
+const HOT_COMPONENTS = new Map<string, HotComponent>();
+
+/**
+ * Registers a template descriptor for Hot Module Replacement.
+ *
+ * Descriptors of templates with unchanged source text are reused, so that
+ * their DOM nodes and nested components aren't recreated when module is
+ * replaced.
+ *
+ * @param id Stable template id.
+ * @param h Hash of the template source text.
+ * @param d Template descriptor.
+ * @returns Template descriptor.
+ */
+export const _hmrT = <T>(id: string, h: string, d: T): T => {
+  const prev = HOT_TEMPLATES.get(id);
+  if (prev !== void 0 && prev.h === h) {
+    return prev.d;
+  }
+  HOT_TEMPLATES.set(id, { h, d });
+  return d;
+};
+
+/**
+ * Registers a component for Hot Module Replacement.
+ *
+ * When component is replaced, all component versions are using the latest
+ * component factory function and mounted instances are recreated with it.
+ *
+ * @param id Stable component id.
+ * @param c Component factory.
+ * @returns Component factory that is shared by all component versions.
+ */
+export const _hmrC = <F extends (p?: any) => VComponent>(id: string, c: F): F => {
+  const d = c().d as ComponentDescriptor;
+  const hot = HOT_COMPONENTS.get(id);
+  if (hot === void 0) {
+    const h: HotComponent = { c, d, f: d.p1, i: new Set() };
+    (d as { p1: ComponentFactoryFn }).p1 = (component) => {
+      h.i.add(component);
+      useUnmount(component, () => { h.i.delete(component); });
+      return h.f(component);
+    };
+    HOT_COMPONENTS.set(id, h);
+    return c;
+  }
+
+  hot.f = d.p1;
+  (hot.d as { p2: ComponentDescriptor["p2"] }).p2 = d.p2;
+  for (const component of Array.from(hot.i)) {
+    const unmountHooks = component.s2;
+    component.s2 = null;
+    if (unmountHooks !== null) {
+      if (typeof unmountHooks === "function") {
+        unmountHooks();
+      } else {
+        for (let i = 0; i < unmountHooks.length; i++) {
+          unmountHooks[i]();
+        }
+      }
+    }
+    component.s1 = hot.d.p1(component);
+    invalidate(component);
+  }
+  return hot.c as F;
+};
@@ -29,6 +29,25 @@ export interface TemplateData {
   s: StateOpCode[],
   /** Array of string values that stores keys for dynamic properties. */
   d: any[],
+  /** Template source information, available in dev mode. */
+  m?: TemplateDebugInfo,
+}
+
+/**
+ * Template source information that is attached to template descriptors by
+ * the template compiler in dev mode.
+ */
+export interface TemplateDebugInfo {
+  /** Source file. */
+  file: string,
+  /** Line of the tagged template (1-based). */
+  line: number,
+  /** Column of the tagged template (1-based). */
+  column: number,
+  /** Template source text. */
+  template: string,
+  /** Source text of template expressions. */
+  exprs: string[],
 }
 
 /**
@@ -90,6 +109,7 @@ export const enum CommonPropType {
   ClassName = 0,
   TextContent = 1,
   InnerHTML = 2,
+  Ref = 3,
 }
 
 /**
@@ -104,6 +124,11 @@ export const enum CommonPropType {
  *       input:6,  // Expr index
  *       data:..,   // Common Property Type
  *     }
+ *     PropOpCode(Event) {
+ *       type:3,   // Event
+ *       input:6,  // Expr index
+ *       data:..,  // Event Modifiers and Data Index
+ *     }
  *     PropOpCode(..) {
  *       type:3,
  *       input:6,  // Expr index
@@ -137,6 +162,36 @@ export const enum PropOpCode {
   Mask6 = (1 << 6) - 1,
 }
 
+/**
+ * Event modifiers.
+ *
+ *     PropOpCode(Event).data {
+ *       modifiers:6,
+ *       key:..,      // Data Index
+ *     }
+ *
+ *     modifiers = `data & Mask6`
+ *     key = `data >> KeyShift`
+ */
+export const enum EventModifier {
+  /** `{ passive: true }` */
+  Passive = 1,
+  /** `{ capture: true }` */
+  Capture = 1 << 1,
+  /** `{ once: true }` */
+  Once = 1 << 2,
+  /** `event.preventDefault()` */
+  Prevent = 1 << 3,
+  /** `event.stopPropagation()` */
+  Stop = 1 << 4,
+  /** Ignores events dispatched from child nodes. */
+  Self = 1 << 5,
+  /** Modifiers that require a handler wrapper. */
+  WrapMask = Prevent | Stop | Self,
+  KeyShift = 6,
+  Mask6 = (1 << 6) - 1,
+}
+
 /**
  * Template child opcodes.
  *
@@ -1,5 +1,6 @@
 import {
   TemplateFlags, ChildOpCode, CommonPropType, PropOpCode, StateOpCode,
+  EventModifier,
 } from "../lib/template.js";
 import {
   type INode, type ITemplate, type INodeElement, type ITemplateType,
@@ -18,6 +19,8 @@ import {
   type SNode, SNodeFlags,
   VOID_ELEMENTS,
   createSNode,
+  htmlBooleanAttributeProperty,
+  isHTMLFormStateAttribute,
 } from "./shared.js";
 
 export interface TemplateCompilationArtifact {
@@ -117,7 +120,7 @@ const compileRootElement = (
   const state = emitStateOpCodes(sRoot);
   // Emits props OpCodes and traverses tree in DFS LTR order.
   const data: string[] = [];
-  const props = emitPropsOpCodes(sRoot, data, exprMap);
+  const props = emitPropsOpCodes(sRoot, type === TEMPLATE_TYPE_SVG, data, exprMap);
   // Emits child OpCodes and traverses tree in DFS RTL order.
   const child = emitChildOpCodes(sRoot, exprMap);
 
@@ -299,24 +302,30 @@ const getDataIndex = (
 
 const emitPropsOpCodes = (
   root: SNode,
+  svg: boolean,
   data: string[],
   exprMap: Map<number, number>,
 ): number[] => {
   const dataMap = new Map<string, number>();
   const opCodes: number[] = [];
-  _emitPropsOpCodes(opCodes, root, true, data, dataMap, exprMap);
+  _emitPropsOpCodes(opCodes, root, true, svg, data, dataMap, exprMap);
   return opCodes;
 };
 const _emitPropsOpCodes = (
   opCodes: number[],
   node: SNode,
   isRoot: boolean,
+  svg: boolean,
   data: string[],
   dataMap: Map<string, number>,
   exprMap: Map<number, number>,
 ) => {
   const iNode = node.node;
   if (iNode.type === NODE_TYPE_ELEMENT) {
+    // HTML attributes aren't normalized in SVG and MathML namespaces.
+    if (iNode.tag === "svg" || iNode.tag === "math") {
+      svg = true;
+    }
     if (node.propsExprs > 0) {
       if (isRoot === false) {
         opCodes.push(PropOpCode.SetNode | (node.stateIndex << PropOpCode.DataShift));
@@ -331,12 +340,31 @@ const _emitPropsOpCodes = (
             case PROPERTY_TYPE_ATTRIBUTE:
               const exprIndex = exprMap.get(value)!;
               if (exprIndex !== -1) {
+                const property = svg === false ? htmlBooleanAttributeProperty(iNode.tag, key) : void 0;
                 if (key === "class") {
                   opCodes.push(
                     PropOpCode.Common |
                     (CommonPropType.ClassName << PropOpCode.DataShift) |
                     (exprIndex << PropOpCode.InputShift)
                   );
+                } else if (key === "ref") {
+                  opCodes.push(
+                    PropOpCode.Common |
+                    (CommonPropType.Ref << PropOpCode.DataShift) |
+                    (exprIndex << PropOpCode.InputShift)
+                  );
+                } else if (property !== void 0) {
+                  opCodes.push(
+                    PropOpCode.Property |
+                    (getDataIndex(data, dataMap, property) << PropOpCode.DataShift) |
+                    (exprIndex << PropOpCode.InputShift)
+                  );
+                } else if (svg === false && isHTMLFormStateAttribute(iNode.tag, key)) {
+                  opCodes.push(
+                    PropOpCode.DiffDOMProperty |
+                    (getDataIndex(data, dataMap, key) << PropOpCode.DataShift) |
+                    (exprIndex << PropOpCode.InputShift)
+                  );
                 } else {
                   opCodes.push(
                     PropOpCode.Attribute |
@@ -398,7 +426,7 @@ const _emitPropsOpCodes = (
             case PROPERTY_TYPE_EVENT:
               opCodes.push(
                 PropOpCode.Event |
-                (getDataIndex(data, dataMap, key) << PropOpCode.DataShift) |
+                (getDataIndex(data, dataMap, key) << (PropOpCode.DataShift + EventModifier.KeyShift)) |
                 (exprMap.get(value)! << PropOpCode.InputShift)
               );
               break;
@@ -416,7 +444,7 @@ const _emitPropsOpCodes = (
     const children = node.children;
     if (children !== null) {
       for (let i = 0; i < children.length; i++) {
-        _emitPropsOpCodes(opCodes, children[i], false, data, dataMap, exprMap);
+        _emitPropsOpCodes(opCodes, children[i], false, svg, data, dataMap, exprMap);
       }
     }
   }
@@ -93,3 +93,54 @@ export const createSNode = (node: INodeElement, flags: number): SNode<INodeEleme
 };
 
 export const VOID_ELEMENTS = /^(audio|video|embed|input|param|source|textarea|track|area|base|link|meta|br|col|hr|img|wbr)$/;
+
+/**
+ * Should be kept in sync with `HTML_BOOLEAN_ATTRIBUTES` in
+ * `crates/ivi_compiler/src/tpl/html.rs`, `tests/compiler/boolean-attributes`
+ * checks that both tables are the same.
+ */
+const HTML_BOOLEAN_ATTRIBUTES: Record<string, [property: string, tags: RegExp | null]> = {
+  hidden: ["hidden", null],
+  inert: ["inert", null],
+  autofocus: ["autofocus", null],
+  disabled: ["disabled", /^(button|fieldset|input|optgroup|option|select|textarea)$/],
+  readonly: ["readOnly", /^(input|textarea)$/],
+  required: ["required", /^(input|select|textarea)$/],
+  multiple: ["multiple", /^(input|select)$/],
+  formnovalidate: ["formNoValidate", /^(button|input)$/],
+  novalidate: ["noValidate", /^form$/],
+  open: ["open", /^(details|dialog)$/],
+  reversed: ["reversed", /^ol$/],
+  ismap: ["isMap", /^img$/],
+  allowfullscreen: ["allowFullscreen", /^iframe$/],
+  default: ["default", /^track$/],
+  async: ["async", /^script$/],
+  defer: ["defer", /^script$/],
+  nomodule: ["noModule", /^script$/],
+  autoplay: ["autoplay", /^(audio|video)$/],
+  controls: ["controls", /^(audio|video)$/],
+  loop: ["loop", /^(audio|video)$/],
+  playsinline: ["playsInline", /^video$/],
+};
+
+/**
+ * Returns a DOM property that reflects a boolean attribute, so that assigning
+ * any falsy value removes the attribute.
+ */
+export const htmlBooleanAttributeProperty = (tag: string, key: string): string | undefined => {
+  const entry = Object.hasOwn(HTML_BOOLEAN_ATTRIBUTES, key) ? HTML_BOOLEAN_ATTRIBUTES[key] : void 0;
+  if (entry !== void 0 && (entry[1] === null || entry[1].test(tag))) {
+    return entry[0];
+  }
+  return void 0;
+};
+
+/**
+ * Returns `true` when an attribute only sets the initial state of a form
+ * control and its value should be diffed against the DOM property instead.
+ */
+export const isHTMLFormStateAttribute = (tag: string, key: string): boolean => (
+  (tag === "input" && (key === "checked" || key === "value")) ||
+  ((tag === "textarea" || tag === "select") && key === "value") ||
+  (tag === "option" && key === "selected")
+);
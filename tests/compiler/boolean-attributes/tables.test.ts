@@ -0,0 +1,41 @@
+import { expect, test } from "bun:test";
+import * as path from "node:path";
+
+// Boolean attribute tables are duplicated in the template compiler and in the
+// runtime template compiler.
+const root = path.join(import.meta.dir, "../../..");
+
+type Entry = [key: string, property: string, tags: string[] | null];
+
+const table = (src: string, name: string) => {
+  const start = src.indexOf(name);
+  return src.slice(start, src.indexOf(";", start));
+};
+
+// ("disabled", "disabled", Some(&["button", "input"])),
+const compilerBooleanAttributes = async (): Promise<Entry[]> => {
+  const src = await Bun.file(path.join(root, "crates/ivi_compiler/src/tpl/html.rs")).text();
+  const re = /\(\s*"(\w+)",\s*"(\w+)",\s*(?:None|Some\(&\[([^\]]*)\]\))\s*,?\s*\)/g;
+  return Array.from(table(src, "const HTML_BOOLEAN_ATTRIBUTES").matchAll(re), (m) => [
+    m[1]!,
+    m[2]!,
+    m[3] === void 0 ? null : m[3].split(",").map((t) => t.trim().replace(/"/g, "")),
+  ]);
+};
+
+// disabled: ["disabled", /^(button|input)$/],
+const runtimeBooleanAttributes = async (): Promise<Entry[]> => {
+  const src = await Bun.file(path.join(root, "packages/ivi/src/template/shared.ts")).text();
+  const re = /(\w+): \["(\w+)", (?:null|\/\^\(?([\w|]+)\)?\$\/)\]/g;
+  return Array.from(table(src, "const HTML_BOOLEAN_ATTRIBUTES").matchAll(re), (m) => [
+    m[1]!,
+    m[2]!,
+    m[3] === void 0 ? null : m[3].split("|"),
+  ]);
+};
+
+test(`compiler/boolean-attributes/tables`, async () => {
+  const compiler = await compilerBooleanAttributes();
+  expect(compiler.length).toBeGreaterThan(0);
+  expect(await runtimeBooleanAttributes()).toEqual(compiler);
+});
@@ -1,8 +1,8 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const STRINGS = ["a"];
 const _TPL_ = _T(_dedupe(_hE("div")), 1, _dedupe([2]), _EMPTY_ARRAY, _EMPTY_ARRAY);
 const c = component(() => {
-	return (v) => _t(_TPL_, [v]);
+	return _hoist((v) => _t(_TPL_, [v]));
 });
@@ -0,0 +1,9 @@
+import { component, html } from "ivi";
+
+const C = component((c) => {
+	const onClick = () => {};
+	return ({ title, items }) => html`
+		<h1 @click=${onClick}>${title}</h1>
+		<ul>${items.map((item) => html`<li>${item}</li>`)}</ul>
+	`;
+});
@@ -0,0 +1,35 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _dev } from "ivi";
+const _TPL_ = /* @__PURE__ */ _dev(/* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _T(/* @__PURE__ */ _hE("li"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY)), {
+	file: "src/01-dev/input.js",
+	line: 7,
+	column: 29,
+	template: "<li>${item}</li>",
+	exprs: ["item"]
+});
+const _TPL_2 = /* @__PURE__ */ _dev(/* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _T(/* @__PURE__ */ _hE("h1"), 65, [6], [4], _EMPTY_ARRAY, ["click"])), {
+	file: "src/01-dev/input.js",
+	line: 5,
+	column: 31,
+	template: "\n		<h1 @click=${onClick}>${title}</h1>\n		<ul>${items.map((item) => html`<li>${item}</li>`)}</ul>\n	",
+	exprs: [
+		"onClick",
+		"title",
+		"items.map((item) => html`<li>${item}</li>`)"
+	]
+});
+const _TPL_3 = /* @__PURE__ */ _dev(/* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _T(/* @__PURE__ */ _hE("ul"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY)), {
+	file: "src/01-dev/input.js",
+	line: 5,
+	column: 31,
+	template: "\n		<h1 @click=${onClick}>${title}</h1>\n		<ul>${items.map((item) => html`<li>${item}</li>`)}</ul>\n	",
+	exprs: [
+		"onClick",
+		"title",
+		"items.map((item) => html`<li>${item}</li>`)"
+	]
+});
+const C = component((c) => {
+	const onClick = () => {};
+	return ({ title, items }) => [_t(_TPL_2, [onClick, title]), _t(_TPL_3, [items.map((item) => _t(_TPL_, [item]))])];
+});
@@ -0,0 +1,3 @@
+import { html } from "ivi";
+
+const a = (tag, v) => html`<${tag} class="a">${v}</>`;
@@ -0,0 +1,13 @@
+import { _Td, _hD, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _dev } from "ivi";
+const _TPL_ = /* @__PURE__ */ _dev(/* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _Td(/* @__PURE__ */ _hD([
+	`<`,
+	` class="a"></`,
+	`>`
+]), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY)), {
+	file: "src/02-dynamic-tag/input.js",
+	line: 3,
+	column: 23,
+	template: "<${tag} class=\"a\">${v}</>",
+	exprs: ["tag", "v"]
+});
+const a = (tag, v) => _t(_TPL_(tag), [v]);
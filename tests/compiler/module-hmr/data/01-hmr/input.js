@@ -0,0 +1,7 @@
+import { component, html } from "ivi";
+
+export const Item = component(() => (text) => html`<li>${text}</li>`);
+
+export const App = component(() => (items) => html`
+	<ul>${items.map(Item)}</ul>
+`);
@@ -0,0 +1,9 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _hmrT, _hmrC } from "ivi";
+const _TPL_ = _hmrT("bcdd11f7ece0d52b", "80c950a8703de6b3", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("li"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+export const Item = _hmrC("cb3d5c5aa5f3cf24", component(() => (text) => _t(_TPL_, [text])));
+const _TPL_2 = _hmrT("65969c9f00a5ab17", "84429e35343b3b1c", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("ul"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+export const App = _hmrC("73f6e701b9b8a510", component(() => (items) => _t(_TPL_2, [items.map(Item)])));
+if (import.meta.hot) {
+	import.meta.hot.accept();
+}
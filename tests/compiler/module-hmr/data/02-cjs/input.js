@@ -0,0 +1,3 @@
+const { component, html } = require("ivi");
+
+exports.App = component(() => (text) => html`<div>${text}</div>`);
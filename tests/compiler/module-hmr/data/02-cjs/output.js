@@ -0,0 +1,4 @@
+const { _T, _hE, _t, EMPTY_ARRAY: _EMPTY_ARRAY, _hmrT, _hmrC } = require("ivi");
+const { component } = require("ivi");
+const _TPL_ = _hmrT("3068a455699a42be", "c7b79374f2b4c1d0", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+exports.App = _hmrC("3ec8eeb822ad3cb7", component(() => (text) => _t(_TPL_, [text])));
@@ -0,0 +1,5 @@
+import { component, html } from "ivi";
+
+export const Item = component(() => (text) => html`<li>${text}</li>`);
+
+export const row = (text) => html`<tr>${text}</tr>`;
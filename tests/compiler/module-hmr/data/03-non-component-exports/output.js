@@ -0,0 +1,6 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _hmrT, _hmrC } from "ivi";
+const _TPL_ = _hmrT("0116a6d51e2a597d", "80c950a8703de6b3", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("li"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+export const Item = _hmrC("0f76f137d73d5376", component(() => (text) => _t(_TPL_, [text])));
+const _TPL_2 = _hmrT("a9d0317c31ef2f68", "2132b12ee4477a59", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("tr"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+export const row = (text) => _t(_TPL_2, [text]);
@@ -0,0 +1,6 @@
+import { component, html } from "ivi";
+
+const Item = component(() => (text) => html`<li>${text}</li>`);
+
+export { Item };
+export default component(() => (items) => html`<ul>${items.map(Item)}</ul>`);
@@ -0,0 +1,10 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _hmrT, _hmrC } from "ivi";
+const _TPL_ = _hmrT("d36d1a541fca2295", "80c950a8703de6b3", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("li"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+const Item = _hmrC("e1cd64b6d4dd1c8e", component(() => (text) => _t(_TPL_, [text])));
+export { Item };
+const _TPL_2 = _hmrT("7c26a4fb338ef881", "5ad2ba75781cbed1", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("ul"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+export default _hmrC("8a86ef5de8a1f27a", component(() => (items) => _t(_TPL_2, [items.map(Item)])));
+if (import.meta.hot) {
+	import.meta.hot.accept();
+}
@@ -0,0 +1,7 @@
+import { component, html } from "ivi";
+
+const make = (text) => component(() => () => html`<p>${text}</p>`);
+
+export const A = make("a");
+export const B = make("b");
+export const C = component(() => () => html`<div>${A()}${B()}</div>`);
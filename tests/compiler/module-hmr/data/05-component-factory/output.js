@@ -0,0 +1,8 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY, _hmrT, _hmrC } from "ivi";
+const _TPL_ = _hmrT("77eb73e189479442", "df4161232fe42d13", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("p"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY));
+const make = (text) => component(() => () => _t(_TPL_, [text]));
+export const A = make("a");
+export const B = make("b");
+const _TPL_2 = _hmrT("20a4fe889d0c6a2e", "051c6f36c6893c72", /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 129, _EMPTY_ARRAY, [4, 0], _EMPTY_ARRAY));
+export const C = _hmrC("864bbe44425a8e3b", component(() => () => _t(_TPL_2, [A(), B()])));
@@ -0,0 +1,7 @@
+import { view, h } from "@app/ui";
+import { html } from "ivi";
+
+const c = view(() => {
+	return (v) => h`<div>${v}</div>`;
+});
+const d = html`<span></span>`;
\ No newline at end of file
@@ -0,0 +1,8 @@
+import { view } from "@app/ui";
+import { html } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "@app/ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+const c = view(() => {
+	return (v) => _t(_TPL_, [v]);
+});
+const d = html`<span></span>`;
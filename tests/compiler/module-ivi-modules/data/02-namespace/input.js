@@ -0,0 +1,6 @@
+import * as ui from "@app/ui";
+import * as ivi from "@app/ivi";
+
+const a = ui.h`<div></div>`;
+const b = ivi.html`<span></span>`;
+const c = ui.html`<p></p>`;
\ No newline at end of file
@@ -0,0 +1,5 @@
+import { h } from "./ui";
+import * as ui from "./ui.js";
+
+const a = h`<div></div>`;
+const b = ui.h`<span></span>`;
\ No newline at end of file
@@ -0,0 +1,6 @@
+import * as ui from "./ui.js";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "@app/ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY);
+const a = _t(_TPL_);
+const _TPL_2 = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("span"), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY);
+const b = _t(_TPL_2);
@@ -0,0 +1,5 @@
+import { h } from "./ui.js";
+import { h as styles } from "./ui.styles";
+
+const a = h`<div></div>`;
+const b = styles`<span></span>`;
@@ -0,0 +1,5 @@
+import { h as styles } from "./ui.styles";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "@app/ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY);
+const a = _t(_TPL_);
+const b = styles`<span></span>`;
@@ -0,0 +1,34 @@
+import { beforeEach, expect, test } from "bun:test";
+import { readdir } from "node:fs/promises";
+import * as path from "node:path";
+import { TemplateCompiler } from "@ivi/compiler";
+import { normalizeNewlines } from "../normalize.js";
+
+
+const units = path.join(import.meta.dir, "data");
+const entries = await readdir(units, { recursive: true });
+for (const entry of entries) {
+  try {
+    const input = await Bun.file(path.join(units, entry, "input.js")).text();
+
+    test(`compiler/module-ivi-modules/${entry}`, async () => {
+      const compiler = new TemplateCompiler({
+        oveo: false,
+        dedupeStrings: false,
+        iviModules: [
+          { specifier: "@app/ivi" },
+          { specifier: "@app/ui", exports: { view: "component", h: "html" } },
+        ],
+        runtimeModule: "@app/ivi",
+        reExports: {
+          [path.join(units, "03-re-export", "ui.ts")]: { h: "html" },
+          [path.join(units, "04-re-export-extension", "ui.ts")]: { h: "html" },
+        },
+      });
+      const output = Bun.file(path.join(units, entry, "output.js"));
+      const moduleResult = await compiler.transform(input, "js", path.join(units, entry, "input.js"));
+      expect(normalizeNewlines(moduleResult.code)).toBe(normalizeNewlines(await output.text()));
+    });
+  } catch (err) {
+  }
+}
@@ -0,0 +1,6 @@
+import { component, html } from "ivi";
+
+const C = component((c) => {
+	const onClick = () => {};
+	return (v) => html`<div class="a" @click=${() => onClick(v)}>${v}</div>`;
+});
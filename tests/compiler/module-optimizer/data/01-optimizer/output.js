@@ -0,0 +1,8 @@
+import { component } from "ivi";
+import { _T, _hN, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist } from "@app/optimizer";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hN(`<div class="a"></div>`), 65, [6], [4], _EMPTY_ARRAY, ["click"]);
+const C = component((c) => {
+	const onClick = () => {};
+	return _hoist((v) => _t(_TPL_, [_hoist(() => onClick(v)), v]));
+});
@@ -1,6 +1,6 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-const _TPL_ = __IVI_TPL__(_T(_hE("div"), 1, [6], _EMPTY_ARRAY, _EMPTY_ARRAY, ["a"]));
+const _TPL_ = /* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, [6], _EMPTY_ARRAY, _EMPTY_ARRAY, ["a"]));
 const c = component(() => {
 	return (v) => _t(_TPL_, [v]);
 });
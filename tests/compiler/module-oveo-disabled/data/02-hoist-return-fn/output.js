@@ -1,4 +1,4 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-const _TPL_ = __IVI_TPL__(_T(_hE("a"), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_ = /* @__PURE__ */ __IVI_TPL__(/* @__PURE__ */ _T(/* @__PURE__ */ _hE("a"), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
 const c = component(() => (v) => _t(_TPL_));
@@ -0,0 +1,101 @@
+import { expect, test } from "bun:test";
+import { TemplateCompiler } from "@ivi/compiler";
+
+const A = `import { html } from "ivi";
+export const a = (v) => html\`<div class="a" id=\${v}><span>text</span></div>\`;
+export const b = (v) => html\`<p title=\${v}></p>\`;
+`;
+
+const B = `import { html } from "ivi";
+export const c = (v) => html\`<div class="a" id=\${v}><span>text</span></div>\`;
+`;
+
+test(`compiler/module-report/disabled`, async () => {
+  const compiler = new TemplateCompiler();
+  await compiler.transform(A, null, "a.js");
+  expect(() => compiler.report()).toThrow();
+});
+
+test(`compiler/module-report/empty`, () => {
+  const compiler = new TemplateCompiler({ report: true });
+  const report = compiler.report();
+  expect(report.modules).toBe(0);
+  expect(report.moduleReports).toEqual([]);
+  expect(report.duplicates).toEqual([]);
+});
+
+test(`compiler/module-report/report`, async () => {
+  const compiler = new TemplateCompiler({ report: true });
+  const result = await compiler.transform(A, null, "a.js");
+  expect(result.templates).toBeUndefined();
+  await compiler.transform(B, null, "b.js");
+  const report = compiler.report();
+  expect(report).toEqual({
+    modules: 2,
+    templates: 3,
+    blocks: 3,
+    staticsSize: 77,
+    propOpCodes: { arrays: 3, opCodes: 3 },
+    childOpCodes: { arrays: 0, opCodes: 0 },
+    stateOpCodes: { arrays: 0, opCodes: 0 },
+    strings: { unique: 2, references: 3, deduped: 1, savings: 5 },
+    moduleReports: [
+      {
+        file: "a.js",
+        templates: 2,
+        staticsSize: 39,
+        largest: [
+          { file: "a.js", line: 2, column: 25, staticsSize: 38 },
+          { file: "a.js", line: 3, column: 25, staticsSize: 1 },
+        ],
+      },
+      {
+        file: "b.js",
+        templates: 1,
+        staticsSize: 38,
+        largest: [
+          { file: "b.js", line: 2, column: 25, staticsSize: 38 },
+        ],
+      },
+    ],
+    duplicates: [
+      {
+        staticsSize: 38,
+        locations: [
+          { file: "a.js", line: 2, column: 25, staticsSize: 38 },
+          { file: "b.js", line: 2, column: 25, staticsSize: 38 },
+        ],
+      },
+    ],
+  });
+  expect(JSON.parse(JSON.stringify(report))).toEqual(report);
+});
+
+test(`compiler/module-report/retransform`, async () => {
+  const compiler = new TemplateCompiler({ report: true });
+  await compiler.transform(A, null, "a.js");
+  await compiler.transform(B, null, "b.js");
+  await compiler.transform(`import { html } from "ivi";`, null, "b.js");
+  const report = compiler.report();
+  expect(report.modules).toBe(1);
+  expect(report.duplicates).toEqual([]);
+});
+
+test(`compiler/module-report/anonymous`, async () => {
+  const compiler = new TemplateCompiler({ report: true });
+  await compiler.transform(A, "js");
+  await compiler.transform(B, "js");
+  const report = compiler.report();
+  expect(report.modules).toBe(0);
+  expect(report.duplicates).toEqual([]);
+});
+
+test(`compiler/module-report/build-start`, async () => {
+  const compiler = new TemplateCompiler({ report: true });
+  await compiler.transform(A, null, "a.js");
+  compiler.buildStart();
+  await compiler.transform(B, null, "b.js");
+  const report = compiler.report();
+  expect(report.modules).toBe(1);
+  expect(report.moduleReports.map((m) => m.file)).toEqual(["b.js"]);
+});
@@ -0,0 +1,57 @@
+import { expect, test } from "bun:test";
+import { TemplateCompiler } from "@ivi/compiler";
+
+test(`compiler/module-templates/disabled`, async () => {
+  const compiler = new TemplateCompiler();
+  const moduleResult = await compiler.transform(`import { html } from "ivi"; html\`<div></div>\`;`, "js");
+  expect(moduleResult.templates).toBeUndefined();
+});
+
+test(`compiler/module-templates/info`, async () => {
+  const compiler = new TemplateCompiler({ templates: true });
+  const moduleResult = await compiler.transform(
+    `import { html } from "ivi";
+
+const a = (v) => html\`
+  <div class="a" id=\${v.id}>
+    <span>\${v.text}</span>
+  </div>
+  \${v.b}
+  <p>\${v.c}</p>
+\`;
+`,
+    "js",
+  );
+  expect(moduleResult.templates).toEqual([
+    {
+      start: 46,
+      end: 143,
+      line: 3,
+      column: 18,
+      kind: "html",
+      roots: 3,
+      blocks: [
+        {
+          element: false,
+          staticsSize: 34,
+          stateSlots: 2,
+          childSlots: 1,
+          propOpCodes: 1,
+          childOpCodes: 2,
+          stateOpCodes: 1,
+          strings: ["id"],
+        },
+        {
+          element: true,
+          staticsSize: 1,
+          stateSlots: 1,
+          childSlots: 1,
+          propOpCodes: 0,
+          childOpCodes: 1,
+          stateOpCodes: 0,
+          strings: [],
+        },
+      ],
+    },
+  ]);
+});
@@ -0,0 +1,3 @@
+import { html } from "ivi";
+
+const a = html(["<div></div>"]);
@@ -0,0 +1,2 @@
+import { html } from "ivi";
+const a = html(["<div></div>"]);
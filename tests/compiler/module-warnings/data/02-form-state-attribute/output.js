@@ -0,0 +1,3 @@
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("input"), 1, [4], _EMPTY_ARRAY, _EMPTY_ARRAY, ["checked"]);
+const a = (v) => _t(_TPL_, [v]);
@@ -0,0 +1,26 @@
+import { html } from "ivi";
+
+const a = (name) => html`
+  <div>
+    Hello
+    ${name}
+  </div>
+`;
+const b = (name) => html`
+  <div>
+    ${name}
+    world
+  </div>
+`;
+const c = (name) => html`
+  <div>
+    Hello
+    \v${name}
+  </div>
+`;
+const d = (name) => html`
+  <div>
+    <span>Hello</span>
+    ${name}
+  </div>
+`;
@@ -0,0 +1,9 @@
+import { _T, _hN, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hN(`<div>Hello</div>`), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+const a = (name) => _t(_TPL_, [name]);
+const _TPL_2 = /* @__PURE__ */ _T(/* @__PURE__ */ _hN(`<div>world</div>`), 66, _EMPTY_ARRAY, [5, 0], [1]);
+const b = (name) => _t(_TPL_2, [name]);
+const _TPL_3 = /* @__PURE__ */ _T(/* @__PURE__ */ _hN(`<div>Hello </div>`), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+const c = (name) => _t(_TPL_3, [name]);
+const _TPL_4 = /* @__PURE__ */ _T(/* @__PURE__ */ _hN(`<div><span>Hello</span></div>`), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+const d = (name) => _t(_TPL_4, [name]);
@@ -0,0 +1,5 @@
+import { html } from "ivi";
+
+const a = (v) => html`<div .innerHTML=${v}></div>`;
+const b = (v) => html`<div *innerHTML=${v}></div>`;
+const c = html`<div .innerHTML=${"<b>a</b>"}></div>`;
@@ -0,0 +1,7 @@
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, [1025], _EMPTY_ARRAY, _EMPTY_ARRAY);
+const a = (v) => _t(_TPL_, [v]);
+const _TPL_2 = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, [1025], _EMPTY_ARRAY, _EMPTY_ARRAY);
+const b = (v) => _t(_TPL_2, [v]);
+const _TPL_3 = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 1, [1025], _EMPTY_ARRAY, _EMPTY_ARRAY);
+const c = _t(_TPL_3, ["<b>a</b>"]);
@@ -0,0 +1,4 @@
+export async function load(v) {
+  const { html } = await import("ivi");
+  return html`<div>${v}</div>`;
+}
@@ -0,0 +1,5 @@
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+export async function load(v) {
+	return _t(_TPL_, [v]);
+}
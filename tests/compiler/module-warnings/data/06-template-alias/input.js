@@ -0,0 +1,9 @@
+import { html } from "ivi";
+
+const a = html;
+const b = html;
+const t = b;
+export const c = html;
+export { html as d };
+
+const e = (v) => t`<div>${v}</div>`;
@@ -0,0 +1,9 @@
+import { html } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+const a = html;
+const b = html;
+const t = b;
+export const c = html;
+export { html as d };
+const _TPL_ = /* @__PURE__ */ _T(/* @__PURE__ */ _hE("div"), 65, _EMPTY_ARRAY, [0], _EMPTY_ARRAY);
+const e = (v) => _t(_TPL_, [v]);
@@ -0,0 +1,45 @@
+import { expect, test } from "bun:test";
+import { readdir } from "node:fs/promises";
+import * as path from "node:path";
+import { TemplateCompiler } from "@ivi/compiler";
+import { normalizeNewlines } from "../normalize.js";
+
+// Rendered warnings have `ivi(code)` headers.
+const warningCodes = (warnings: string[]) => (
+  warnings.map((w) => /ivi\(([a-z-]+)\)/.exec(w)?.[1] ?? "").join("\n")
+);
+
+const units = path.join(import.meta.dir, "data");
+const entries = await readdir(units, { recursive: true });
+for (const entry of entries) {
+  try {
+    const input = await Bun.file(path.join(units, entry, "input.js")).text();
+
+    test(`compiler/module-warnings/${entry}`, async () => {
+      const compiler = new TemplateCompiler({ dedupeStrings: false });
+      const output = Bun.file(path.join(units, entry, "output.js"));
+      const warnings = Bun.file(path.join(units, entry, "warnings.txt"));
+      const moduleResult = await compiler.transform(input, "js");
+      expect(normalizeNewlines(moduleResult.code)).toBe(normalizeNewlines(await output.text()));
+      expect(warningCodes(moduleResult.warnings)).toBe(normalizeNewlines(await warnings.text()).trimEnd());
+    });
+  } catch (err) {
+  }
+}
+
+test(`compiler/module-warnings/allow`, async () => {
+  const compiler = new TemplateCompiler({ warnings: { "inner-html": "allow" } });
+  const moduleResult = await compiler.transform(
+    `import { html } from "ivi"; const a = (v) => html\`<div .innerHTML=\${v}></div>\`;`,
+    "js",
+  );
+  expect(moduleResult.warnings).toEqual([]);
+});
+
+test(`compiler/module-warnings/deny`, async () => {
+  const compiler = new TemplateCompiler({ warnings: { "inner-html": "deny" } });
+  await expect(compiler.transform(
+    `import { html } from "ivi"; const a = (v) => html\`<div .innerHTML=\${v}></div>\`;`,
+    "js",
+  )).rejects.toThrow("inner-html");
+});
@@ -1,4 +1,5 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
+import { hoist as _hoist } from "oveo";
 const c = component(() => {
-	return (v) => "a";
+	return _hoist((v) => "a");
 });
@@ -1,7 +1,7 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hN, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<a>b</a>`)), 66, _EMPTY_ARRAY, _dedupe([5, 0]), _dedupe([1])));
 const c = component(() => {
-	return (v) => _t(_TPL_, [v]);
+	return _hoist((v) => _t(_TPL_, [v]));
 });
@@ -0,0 +1,9 @@
+import { component, html, useState } from "ivi";
+
+const c = component((c) => {
+	const [s, setS] = useState(c, 0);
+	if (s() > 10) {
+		return () => html`<b/>`;
+	}
+	return () => html`<a/>`;
+});
\ No newline at end of file
@@ -0,0 +1,12 @@
+import { component, useState } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("b")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_2 = __IVI_TPL__(_T(_dedupe(_hE("a")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const c = component((c) => {
+	const [s, setS] = useState(c, 0);
+	if (s() > 10) {
+		return _hoist(() => _t(_TPL_));
+	}
+	return _hoist(() => _t(_TPL_2));
+});
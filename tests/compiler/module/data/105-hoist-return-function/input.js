@@ -0,0 +1,8 @@
+import { component, html } from "ivi";
+
+const c = component(function (c) {
+	const onClick = () => {};
+	return function (v) {
+		return html`<a @click=${onClick}/>`;
+	};
+});
\ No newline at end of file
@@ -0,0 +1,10 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("a")), 1, _dedupe([6]), _EMPTY_ARRAY, _EMPTY_ARRAY, ["click"]));
+const c = component(function(c) {
+	const onClick = () => {};
+	return _hoist(function(v) {
+		return _t(_TPL_, [_hoist(onClick)]);
+	});
+});
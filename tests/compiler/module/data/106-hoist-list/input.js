@@ -0,0 +1,9 @@
+import { component, html, List } from "ivi";
+
+const getKey = (entry) => entry.id;
+const c = component(() => {
+	return (entries) => html`
+		<ul>${List(entries, (entry) => entry.id, (entry) => html`<li>${entry.text}</li>`)}</ul>
+		<ul>${List(entries, getKey, (entry) => html`<li>${entry.text}</li>`)}</ul>
+	`;
+});
@@ -0,0 +1,11 @@
+import { component, List } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const getKey = (entry) => entry.id;
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("li")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const _TPL_2 = __IVI_TPL__(_T(_dedupe(_hE("li")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const _TPL_3 = __IVI_TPL__(_T(_dedupe(_hE("ul")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const _TPL_4 = __IVI_TPL__(_T(_dedupe(_hE("ul")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((entries) => [_t(_TPL_3, [List(entries, _hoist((entry) => entry.id), _hoist((entry) => _t(_TPL_, [entry.text])))]), _t(_TPL_4, [List(entries, getKey, _hoist((entry) => _t(_TPL_2, [entry.text])))])]);
+});
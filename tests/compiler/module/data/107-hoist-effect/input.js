@@ -0,0 +1,11 @@
+import { component, html, useEffect, useIdleEffect } from "ivi";
+
+const c = component((c) => {
+	const log = useEffect(c, (v) => {
+		console.log(v);
+	}, (a, b) => a === b);
+	const idle = useIdleEffect(c, function () {
+		console.log("idle");
+	});
+	return (v) => (log(v), idle(), html`<div>${v}</div>`);
+});
@@ -0,0 +1,13 @@
+import { component, useEffect, useIdleEffect } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("div")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component((c) => {
+	const log = useEffect(c, _hoist((v) => {
+		console.log(v);
+	}), _hoist((a, b) => a === b));
+	const idle = useIdleEffect(c, _hoist(function() {
+		console.log("idle");
+	}));
+	return _hoist((v) => (log(v), idle(), _t(_TPL_, [v])));
+});
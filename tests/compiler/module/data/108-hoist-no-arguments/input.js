@@ -0,0 +1,4 @@
+import { List, useEffect } from "ivi";
+
+const a = List();
+const b = useEffect();
@@ -0,0 +1,3 @@
+import { List, useEffect } from "ivi";
+const a = List();
+const b = useEffect();
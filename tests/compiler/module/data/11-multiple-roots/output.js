@@ -1,11 +1,11 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("a")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
 const c = component(() => {
-	return (v) => [
+	return _hoist((v) => [
 		"a",
 		_t(_TPL_),
 		"b"
-	];
+	]);
 });
@@ -0,0 +1,5 @@
+import { component, html } from "ivi";
+
+const c = component(() => {
+	return (v) => html`<${v.tag} class="a">${v.text}</>`;
+});
@@ -0,0 +1,11 @@
+import { component } from "ivi";
+import { _Td, _hD, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_Td(_dedupe(_hD([
+	`<`,
+	` class="a"></`,
+	`>`
+])), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((v) => _t(_TPL_(v.tag), [v.text]));
+});
@@ -0,0 +1,8 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("h1")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const _TPL_2 = __IVI_TPL__(_T(_dedupe(_hE("h2")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((v) => _t(v.a ? _TPL_ : _TPL_2, [v.b]));
+});
@@ -1,8 +1,9 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
+import { hoist as _hoist } from "oveo";
 const c = component(() => {
-	return (v) => [
+	return _hoist((v) => [
 		"a",
 		v,
 		"b"
-	];
+	]);
 });
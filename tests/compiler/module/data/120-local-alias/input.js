@@ -0,0 +1,6 @@
+import { component, html } from "ivi";
+
+const h = html;
+const c = component(() => {
+	return (v) => h`<div>${v}</div>`;
+});
\ No newline at end of file
@@ -0,0 +1,8 @@
+import { component, html } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const h = html;
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("div")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((v) => _t(_TPL_, [v]));
+});
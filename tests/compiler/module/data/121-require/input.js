@@ -0,0 +1,6 @@
+const { component, html: h } = require("ivi");
+
+const c = component(() => {
+	return (v) => h`<div>${v}</div>`;
+});
+module.exports = { c };
\ No newline at end of file
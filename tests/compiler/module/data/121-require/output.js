@@ -0,0 +1,8 @@
+const { _T, _hE, _t, EMPTY_ARRAY: _EMPTY_ARRAY } = require("ivi");
+const { hoist: _hoist, dedupe: _dedupe } = require("oveo");
+const { component } = require("ivi");
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("div")), 65, _EMPTY_ARRAY, _dedupe([0]), _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((v) => _t(_TPL_, [v]));
+});
+module.exports = { c };
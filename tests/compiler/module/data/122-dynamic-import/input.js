@@ -0,0 +1,6 @@
+const ivi = await import("ivi");
+
+const c = ivi.component(() => {
+	return (v) => ivi.html`<div>${v}</div>`;
+});
+export { c };
\ No newline at end of file
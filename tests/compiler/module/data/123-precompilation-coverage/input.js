@@ -0,0 +1,8 @@
+import { component, html } from "ivi";
+
+let h = html;
+h = null;
+const c = component(() => {
+	return (v) => html(["<div></div>"]);
+});
+const d = [html];
\ No newline at end of file
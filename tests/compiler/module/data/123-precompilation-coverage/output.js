@@ -0,0 +1,8 @@
+import { component, html } from "ivi";
+import { hoist as _hoist } from "oveo";
+let h = html;
+h = null;
+const c = component(() => {
+	return _hoist((v) => html(["<div></div>"]));
+});
+const d = [html];
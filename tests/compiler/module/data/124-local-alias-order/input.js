@@ -0,0 +1,8 @@
+import { html } from "ivi";
+
+function App() {
+  return t`<div></div>`;
+}
+
+const t = h;
+const h = html;
@@ -0,0 +1,9 @@
+import { html } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("div")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+function App() {
+	return _t(_TPL_);
+}
+const t = h;
+const h = html;
@@ -0,0 +1,9 @@
+import { component, html } from "ivi";
+
+const c = component(() => {
+	return (v) => html`
+		<${v.input ? "input" : "div"} class="a" />
+		<${v.input ? "input" : "br"} />
+		<${v.tag} class="b" />
+	`;
+});
@@ -0,0 +1,15 @@
+import { component } from "ivi";
+import { _T, _Td, _hN, _hE, _hD, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<input class="a">`)), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_2 = __IVI_TPL__(_T(_dedupe(_hN(`<div class="a"></div>`)), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_3 = __IVI_TPL__(_T(_dedupe(_hE("input")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_4 = __IVI_TPL__(_T(_dedupe(_hE("br")), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const _TPL_5 = __IVI_TPL__(_Td(_dedupe(_hD([`<`, ` class="b">`])), 1, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
+const c = component(() => {
+	return _hoist((v) => [
+		_t(v.input ? _TPL_ : _TPL_2),
+		_t(v.input ? _TPL_3 : _TPL_4),
+		_t(_TPL_5(v.tag))
+	]);
+});
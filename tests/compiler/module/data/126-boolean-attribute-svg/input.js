@@ -0,0 +1,4 @@
+import { html } from "ivi";
+
+const a = (x) => html`<div hidden=${x}><svg><rect hidden=${x} /></svg></div>`;
+const b = (x) => html`<math hidden=${x}><mi hidden=${x}>a</mi></math>`;
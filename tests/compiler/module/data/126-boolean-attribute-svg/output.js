@@ -0,0 +1,14 @@
+import { _T, _hN, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<div><svg><rect></rect></svg></div>`)), 2, _dedupe([
+	3,
+	512,
+	10
+]), _EMPTY_ARRAY, _dedupe([6, 1]), ["hidden"]));
+const a = (x) => _t(_TPL_, [x, x]);
+const _TPL_2 = __IVI_TPL__(_T(_dedupe(_hN(`<math><mi>a</mi></math>`)), 2, _dedupe([
+	2,
+	512,
+	10
+]), _EMPTY_ARRAY, _dedupe([1]), ["hidden"]));
+const b = (x) => _t(_TPL_2, [x, x]);
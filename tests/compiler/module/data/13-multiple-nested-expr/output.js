@@ -1,6 +1,6 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hN, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<div><span></span><span></span></div>`)), 131, _EMPTY_ARRAY, _dedupe([
 	11,
 	4,
@@ -8,5 +8,5 @@ const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<div><span></span><span></span></div>`
 	0
 ]), _dedupe([1, 1])));
 const c = component(() => {
-	return (v) => _t(_TPL_, [v.a, v.b]);
+	return _hoist((v) => _t(_TPL_, [v.a, v.b]));
 });
@@ -1,7 +1,7 @@
-import { component, svg } from "ivi";
+import { component } from "ivi";
 import { _T, _sE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const _TPL_ = __IVI_TPL__(_T(_dedupe(_sE("a")), 4097, _EMPTY_ARRAY, _EMPTY_ARRAY, _EMPTY_ARRAY));
 const c = component(() => {
-	return (v) => _t(_TPL_);
+	return _hoist((v) => _t(_TPL_));
 });
@@ -1,6 +1,6 @@
-import { component, html } from "ivi";
+import { component } from "ivi";
 import { _T, _hN, _t } from "ivi";
-import { dedupe as _dedupe } from "oveo";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
 const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<a><b><c><d></d></c><c><d></d></c><c><d></d></c></b><b><c><d></d></c><c></c><c><d></d></c></b></a>`)), 263, _dedupe([
 	512,
 	1,
@@ -30,7 +30,7 @@ const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<a><b><c><d></d></c><c><d></d></c><c><
 	1
 ])));
 const c = component(() => {
-	return (v) => _t(_TPL_, [
+	return _hoist((v) => _t(_TPL_, [
 		v.d1,
 		v.c1,
 		v.d2,
@@ -38,5 +38,5 @@ const c = component(() => {
 		v.c2,
 		v.c1,
 		v.f
-	]);
+	]));
 });
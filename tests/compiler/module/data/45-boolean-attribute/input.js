@@ -0,0 +1,5 @@
+import { component, html } from "ivi";
+
+const c = component(() => {
+	return (v) => html`<button disabled=${v}></button>`;
+});
\ No newline at end of file
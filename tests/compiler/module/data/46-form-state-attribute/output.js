@@ -0,0 +1,7 @@
+import { component } from "ivi";
+import { _T, _hE, _t, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hE("input")), 1, _dedupe([4]), _EMPTY_ARRAY, _EMPTY_ARRAY, ["checked"]));
+const c = component(() => {
+	return _hoist((v) => _t(_TPL_, [v]));
+});
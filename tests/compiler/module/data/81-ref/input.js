@@ -0,0 +1,8 @@
+import { component, html } from "ivi";
+
+const c = component(() => {
+	return (v) => html`
+	  <div
+		  ref=${v}
+		/>`;
+});
@@ -0,0 +1,5 @@
+import { component, html } from "ivi";
+
+const c = component(() => {
+	return (v) => html`<input type="checkbox" bind:checked=${[v.get, v.set]}/>`;
+});
@@ -0,0 +1,7 @@
+import { component } from "ivi";
+import { _T, _hN, _t, _b, EMPTY_ARRAY as _EMPTY_ARRAY } from "ivi";
+import { hoist as _hoist, dedupe as _dedupe } from "oveo";
+const _TPL_ = __IVI_TPL__(_T(_dedupe(_hN(`<input type="checkbox">`)), 1, _dedupe([4, 32782]), _EMPTY_ARRAY, _EMPTY_ARRAY, ["checked", "change"]));
+const c = component(() => {
+	return _hoist((v) => _t(_TPL_, [v.get(), _b(v.set, "checked")]));
+});
@@ -19,3 +19,11 @@ for (const entry of entries) {
   } catch (err) {
   }
 }
+
+test(`compiler/module/dynamic-tag-void-children`, async () => {
+  const compiler = new TemplateCompiler();
+  await expect(compiler.transform(
+    `import { html } from "ivi"; const a = (v) => html\`<\${v.c ? "input" : "div"} class="a">\${v.x}</>\`;`,
+    "js",
+  )).rejects.toThrow("Void element 'input' can't have children");
+});
@@ -0,0 +1,55 @@
+import { deepStrictEqual, ok, strictEqual } from "node:assert";
+import { beforeEach, describe, test } from "bun:test";
+import { reset } from "@ivi/mock-dom/global";
+import { createRoot } from "ivi/test";
+import { component, useUnmount, _hmrC, _hmrT } from "ivi";
+
+describe("hmr", () => {
+  beforeEach(reset);
+
+  test(`_hmrT same source`, () => {
+    const a = {};
+    const b = {};
+    strictEqual(_hmrT("t1", "h1", a), a);
+    strictEqual(_hmrT("t1", "h1", b), a);
+  });
+
+  test(`_hmrT changed source`, () => {
+    const a = {};
+    const b = {};
+    strictEqual(_hmrT("t2", "h1", a), a);
+    strictEqual(_hmrT("t2", "h2", b), b);
+  });
+
+  test(`_hmrC replace`, () => {
+    let _trace: string[] = [];
+    const v1 = _hmrC("c1", component((c) => {
+      _trace.push("create 1");
+      useUnmount(c, () => { _trace.push("unmount 1"); });
+      return () => {
+        _trace.push("render 1");
+        return null;
+      };
+    }));
+    const root = createRoot();
+    root.update(
+      v1(),
+    );
+    deepStrictEqual(_trace, ["create 1", "render 1"]);
+    _trace = [];
+
+    const v2 = _hmrC("c1", component(() => {
+      _trace.push("create 2");
+      return () => {
+        _trace.push("render 2");
+        return null;
+      };
+    }));
+    strictEqual(v2, v1);
+    deepStrictEqual(_trace, ["unmount 1", "create 2"]);
+    ok(root.isDirty);
+    _trace = [];
+    root.dirtyCheck();
+    deepStrictEqual(_trace, ["render 2"]);
+  });
+});
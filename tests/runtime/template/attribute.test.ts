@@ -177,4 +177,32 @@ describe("@ivi/htm attribute", () => {
       [],
     );
   });
+
+  test(`boolean attribute`, () => {
+    const root = createRoot();
+    const B = (v: any) => html`<button disabled=${v} />`;
+    deepStrictEqual(
+      trace(() => { root.update(B(0)); }),
+      [
+        `createElement("button") => 2`,
+        `[2] Element.setProperty("disabled", 0)`,
+        `[1] Node.insertBefore(2, null)`,
+      ],
+    );
+    deepStrictEqual(trace(() => { root.update(B(true)); }),
+      [`[2] Element.setProperty("disabled", true)`],
+    );
+  });
+
+  test(`form state attribute`, () => {
+    const root = createRoot();
+    const C = (v: any) => html`<input checked=${v} />`;
+    root.update(C(false));
+    deepStrictEqual(trace(() => { root.update(C(true)); }),
+      [
+        `[2] Element.getProperty("checked") => false`,
+        `[2] Element.setProperty("checked", true)`,
+      ],
+    );
+  });
 });
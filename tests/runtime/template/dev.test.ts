@@ -0,0 +1,39 @@
+import { strictEqual, throws } from "node:assert";
+import { beforeEach, describe, test } from "bun:test";
+import { reset } from "@ivi/mock-dom/global";
+import { createRoot } from "ivi/test";
+import { html, _dev } from "ivi";
+import { type ElementDirective } from "ivi";
+
+describe("dev mode", () => {
+  beforeEach(reset);
+  const T = (directive: ElementDirective) => html`<div ${directive} />`;
+  const fail = () => { throw Error("directive"); };
+
+  test(`errors without source information`, () => {
+    const root = createRoot();
+    throws(
+      () => { root.update(T(fail)); },
+      { message: "directive" },
+    );
+  });
+
+  test(`errors with source information`, () => {
+    _dev(T(fail).d, {
+      file: "App.tsx",
+      line: 3,
+      column: 10,
+      template: "<div ${directive} />",
+      exprs: ["directive"],
+    });
+    const root = createRoot();
+    throws(
+      () => { root.update(T(fail)); },
+      (e: any) => {
+        strictEqual(e.message, "Error in template at App.tsx:3:10");
+        strictEqual(e.cause.message, "directive");
+        return true;
+      },
+    );
+  });
+});
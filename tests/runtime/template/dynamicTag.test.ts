@@ -0,0 +1,34 @@
+import { doesNotThrow, strictEqual, throws } from "node:assert";
+import { beforeEach, describe, test } from "bun:test";
+import { reset, trace } from "@ivi/mock-dom/global";
+import { _hD, _sD } from "ivi";
+
+describe("dynamic tag names", () => {
+  beforeEach(reset);
+  const S = ["<", "></", ">"];
+
+  test("valid tag names", () => {
+    doesNotThrow(() => { _hD(S)("h1"); });
+    doesNotThrow(() => { _hD(S)("my-element"); });
+    doesNotThrow(() => { _sD(S)("foreignObject"); });
+  });
+
+  test("invalid tag names", () => {
+    throws(() => { _hD(S)("img src=x onerror=alert(1)"); });
+    throws(() => { _hD(S)("div><script>"); });
+    throws(() => { _hD(S)(""); });
+    throws(() => { _sD(S)("g onload=alert(1)"); });
+  });
+
+  test("void elements", () => {
+    strictEqual(
+      trace(() => { _hD(["<", ` class="a">`])("input")(); })[0],
+      `[-7] Template.innerHTML = "<input class="a">"`,
+    );
+    strictEqual(
+      trace(() => { _hD(["<", ` class="a">`])("div")(); })[0],
+      `[-7] Template.innerHTML = "<div class="a"></div>"`,
+    );
+    throws(() => { _hD(["<", "></", ">"])("br"); });
+  });
+});